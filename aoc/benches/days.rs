@@ -0,0 +1,48 @@
+//! `cargo bench` entry point: times every day (or the days named on the command line) and
+//! compares against the previous run, whose timings are kept next to the build output.
+
+use aoc::bench::{self, Baseline};
+use aoc::days::{self, Day, DAYS};
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    // Cargo passes `--bench`; anything else that isn't a flag picks out days.
+    let selected: Vec<&Day> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .map(|arg| {
+            arg.parse()
+                .ok()
+                .and_then(days::find)
+                .unwrap_or_else(|| panic!("no such day: {}", arg))
+        })
+        .collect();
+    let selected = if selected.is_empty() {
+        DAYS.iter().collect()
+    } else {
+        selected
+    };
+
+    let timings: Vec<_> = selected
+        .into_iter()
+        .map(|day| bench::time_day(day, day.input, 1).unwrap())
+        .collect();
+
+    let path = baseline_path();
+    let previous = Baseline::load(&path).ok();
+    print!("{}", bench::table(&timings, previous.as_ref()));
+
+    let mut baseline = previous.unwrap_or_default();
+    baseline.extend(&timings);
+    baseline.save(&path).unwrap();
+}
+
+/// `target/release/bench-baseline.txt`, found relative to this executable in `deps`.
+fn baseline_path() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent()
+        .and_then(|deps| deps.parent())
+        .unwrap()
+        .join("bench-baseline.txt")
+}
@@ -0,0 +1,193 @@
+//! Timing of each day's parse and parts, shared by `aoc bench` and `cargo bench`.
+
+use crate::days::Day;
+use crate::{ParseError, Part};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::One) => "part1",
+            Stage::Solve(Part::Two) => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Stage> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Solve(Part::One)),
+            "part2" => Some(Stage::Solve(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+/// The fastest time seen for each stage of one day.
+pub struct DayTiming {
+    pub day: usize,
+    pub title: &'static str,
+    pub stages: Vec<(Stage, Duration)>,
+}
+
+impl DayTiming {
+    pub fn get(&self, stage: Stage) -> Option<Duration> {
+        self.stages
+            .iter()
+            .find(|(measured, _)| *measured == stage)
+            .map(|(_, elapsed)| *elapsed)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.stages.iter().map(|(_, elapsed)| *elapsed).sum()
+    }
+}
+
+/// Parses `input` and solves every part of `day`, `runs` times over, keeping the best time of each.
+pub fn time_day(day: &Day, input: &str, runs: usize) -> Result<DayTiming, ParseError> {
+    let mut best: Vec<(Stage, Duration)> = Vec::new();
+    let mut record =
+        |stage: Stage, elapsed: Duration| match best.iter_mut().find(|(seen, _)| *seen == stage) {
+            Some((_, fastest)) => *fastest = elapsed.min(*fastest),
+            None => best.push((stage, elapsed)),
+        };
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = day.parse(black_box(input))?;
+        record(Stage::Parse, start.elapsed());
+
+        for part in day.parts() {
+            let start = Instant::now();
+            black_box(parsed.solve(part));
+            record(Stage::Solve(part), start.elapsed());
+        }
+    }
+
+    Ok(DayTiming {
+        day: day.number,
+        title: day.title,
+        stages: best,
+    })
+}
+
+/// Timings from an earlier run, to compare against.
+///
+/// Stored as one `<day> <stage> <nanoseconds>` line per stage.
+#[derive(Default)]
+pub struct Baseline {
+    stages: HashMap<(usize, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn from_timings(timings: &[DayTiming]) -> Baseline {
+        Baseline {
+            stages: timings
+                .iter()
+                .flat_map(|timing| {
+                    timing
+                        .stages
+                        .iter()
+                        .map(move |&(stage, elapsed)| ((timing.day, stage), elapsed))
+                })
+                .collect(),
+        }
+    }
+
+    /// Replaces the recorded timings of every stage in `timings`, keeping those of other days.
+    pub fn extend(&mut self, timings: &[DayTiming]) {
+        self.stages.extend(Baseline::from_timings(timings).stages);
+    }
+
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed baseline line: {:?}", line),
+            )
+        };
+        let mut stages = HashMap::new();
+        for line in fs::read_to_string(path)?.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [day, stage, nanos] => {
+                    let day = day.parse().map_err(|_| invalid(line))?;
+                    let stage = Stage::from_name(stage).ok_or_else(|| invalid(line))?;
+                    let nanos = nanos.parse().map_err(|_| invalid(line))?;
+                    stages.insert((day, stage), Duration::from_nanos(nanos));
+                }
+                [] => {}
+                _ => return Err(invalid(line)),
+            }
+        }
+        Ok(Baseline { stages })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut entries: Vec<_> = self.stages.iter().collect();
+        entries.sort_by_key(|((day, stage), _)| (*day, stage.name()));
+        let mut contents = String::new();
+        for ((day, stage), elapsed) in entries {
+            writeln!(contents, "{} {} {}", day, stage.name(), elapsed.as_nanos()).unwrap();
+        }
+        fs::write(path, contents)
+    }
+
+    fn total(&self, timing: &DayTiming) -> Option<Duration> {
+        timing
+            .stages
+            .iter()
+            .map(|(stage, _)| self.stages.get(&(timing.day, *stage)))
+            .sum::<Option<Duration>>()
+    }
+}
+
+/// Renders `timings` slowest first, with the change in total time since `baseline` if given.
+pub fn table(timings: &[DayTiming], baseline: Option<&Baseline>) -> String {
+    let mut sorted: Vec<&DayTiming> = timings.iter().collect();
+    sorted.sort_by_key(|timing| std::cmp::Reverse(timing.total()));
+
+    let mut table = format!(
+        "{:<5} {:<26} {:>11} {:>11} {:>11} {:>11} {:>9}\n",
+        "day", "title", "parse ms", "part1 ms", "part2 ms", "total ms", "change"
+    );
+    for timing in sorted {
+        let column = |stage| timing.get(stage).map_or(String::from("-"), millis);
+        let change = baseline
+            .and_then(|baseline| baseline.total(timing))
+            .filter(|before| !before.is_zero())
+            .map_or(String::new(), |before| {
+                let ratio = timing.total().as_secs_f64() / before.as_secs_f64();
+                format!("{:+.1}%", (ratio - 1.0) * 100.0)
+            });
+        writeln!(
+            table,
+            "{:<5} {:<26} {:>11} {:>11} {:>11} {:>11} {:>9}",
+            timing.day,
+            timing.title,
+            column(Stage::Parse),
+            column(Stage::Solve(Part::One)),
+            column(Stage::Solve(Part::Two)),
+            millis(timing.total()),
+            change,
+        )
+        .unwrap();
+    }
+    table
+}
+
+fn millis(elapsed: Duration) -> String {
+    format!("{:.3}", elapsed.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,263 @@
+use crate::{parse_at, ParseError, Part, Solution};
+use std::fmt;
+use std::ops::Range;
+
+const TARGET: i64 = 2020;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+    type Part1 = Product;
+    type Part2 = Product;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        input.lines().map(|s| parse_at(input, s)).collect()
+    }
+
+    fn part1(report: &Vec<i32>) -> Product {
+        first_product(report, Part::One)
+    }
+
+    fn part2(report: &Vec<i32>) -> Product {
+        first_product(report, Part::Two)
+    }
+}
+
+/// The product of the first match for a part, if any entries sum to 2020.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Product {
+    Found(i128),
+    NoMatch,
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Product::Found(product) => write!(f, "{}", product),
+            Product::NoMatch => write!(f, "no entries sum to {}", TARGET),
+        }
+    }
+}
+
+fn first_product(report: &[i32], part: Part) -> Product {
+    match matches(report, part).next() {
+        // At most three `i32`s, which always fit in an `i128`.
+        Some(found) => Product::Found(found.product.expect("Product too large!")),
+        None => Product::NoMatch,
+    }
+}
+
+/// The pairs (part 1) or triples (part 2) of entries summing to 2020.
+pub fn matches(report: &[i32], part: Part) -> KSums {
+    let k = match part {
+        Part::One => 2,
+        Part::Two => 3,
+    };
+    k_sums(report, k, TARGET)
+}
+
+/// Entries of the expense report that add up to the target.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    /// In ascending order.
+    pub entries: Vec<i32>,
+    /// Where each of `entries` is in the report, counting from 1.
+    pub lines: Vec<usize>,
+    /// The entries multiplied together, unless that overflows even an `i128`.
+    pub product: Option<i128>,
+}
+
+/// Finds `k` different entries of `report` summing to `target`.
+pub fn k_sum(report: &[i32], k: usize, target: i64) -> Option<Match> {
+    k_sums(report, k, target).next()
+}
+
+/// Every way of picking `k` different entries of `report` that sum to `target`.
+///
+/// Entries are told apart by line rather than value, so a value appearing twice can be used
+/// twice, and the matches come out in ascending order of their entries.
+pub fn k_sums(report: &[i32], k: usize, target: i64) -> KSums {
+    assert!(k > 0, "a match needs at least one entry");
+    let mut sorted: Vec<(i64, usize)> = report
+        .iter()
+        .enumerate()
+        .map(|(i, &entry)| (entry as i64, i + 1))
+        .collect();
+    sorted.sort_unstable();
+    let mut prefix_sums = vec![0];
+    for (entry, _) in &sorted {
+        prefix_sums.push(prefix_sums.last().unwrap() + entry);
+    }
+    KSums {
+        sorted,
+        prefix_sums,
+        k,
+        target,
+        chosen: Vec::with_capacity(k),
+        started: false,
+        last: 0..0,
+    }
+}
+
+/// The search behind `k_sums`.
+///
+/// It walks the first `k - 1` entries through the sorted report like an odometer, skipping any
+/// prefix whose smallest or largest possible completion misses the target, and binary searches
+/// for the run of entries that completes each remaining prefix.
+pub struct KSums {
+    sorted: Vec<(i64, usize)>,
+    /// `prefix_sums[i]` is the sum of the first `i` sorted entries.
+    prefix_sums: Vec<i64>,
+    k: usize,
+    target: i64,
+    /// Indices into `sorted` of the entries picked so far.
+    chosen: Vec<usize>,
+    started: bool,
+    /// Indices into `sorted` of the entries completing `chosen` not yet reported.
+    last: Range<usize>,
+}
+
+impl KSums {
+    fn window_sum(&self, from: usize, count: usize) -> i64 {
+        self.prefix_sums[from + count] - self.prefix_sums[from]
+    }
+
+    /// Moves `chosen` on to the next `k - 1` entries that might be part of a match.
+    fn next_prefix(&mut self) -> bool {
+        let n = self.sorted.len();
+        let mut descend = !self.started;
+        self.started = true;
+        loop {
+            if descend {
+                if self.chosen.len() == self.k - 1 {
+                    return true;
+                }
+                let next = self.chosen.last().map_or(0, |&i| i + 1);
+                self.chosen.push(next);
+            } else {
+                match self.chosen.last_mut() {
+                    Some(top) => *top += 1,
+                    None => return false,
+                }
+            }
+
+            let top = *self.chosen.last().unwrap();
+            let remaining = self.k - self.chosen.len();
+            let so_far: i64 = self.chosen.iter().map(|&i| self.sorted[i].0).sum();
+            if top + remaining >= n || so_far + self.window_sum(top + 1, remaining) > self.target {
+                // Every later choice here would only be bigger, so back up a level.
+                self.chosen.pop();
+                descend = false;
+            } else {
+                descend = so_far + self.window_sum(n - remaining, remaining) >= self.target;
+            }
+        }
+    }
+
+    fn completions(&self) -> Range<usize> {
+        let start = self.chosen.last().map_or(0, |&i| i + 1);
+        let wanted = self.target - self.chosen.iter().map(|&i| self.sorted[i].0).sum::<i64>();
+        let rest = &self.sorted[start..];
+        let from = rest.partition_point(|&(entry, _)| entry < wanted);
+        let to = rest.partition_point(|&(entry, _)| entry <= wanted);
+        start + from..start + to
+    }
+}
+
+impl Iterator for KSums {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            if let Some(last) = self.last.next() {
+                let picked: Vec<_> = self
+                    .chosen
+                    .iter()
+                    .chain(Some(&last))
+                    .map(|&i| self.sorted[i])
+                    .collect();
+                return Some(Match {
+                    entries: picked.iter().map(|&(entry, _)| entry as i32).collect(),
+                    lines: picked.iter().map(|&(_, line)| line).collect(),
+                    product: picked.iter().try_fold(1i128, |product, &(entry, _)| {
+                        product.checked_mul(entry.into())
+                    }),
+                });
+            }
+            if !self.next_prefix() {
+                return None;
+            }
+            self.last = self.completions();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{k_sum, k_sums, Day1, Product};
+    use crate::Solution;
+
+    const EXAMPLE: [i32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn example() {
+        let pair = k_sum(&EXAMPLE, 2, 2020).unwrap();
+        assert_eq!(
+            (pair.entries, pair.product),
+            (vec![299, 1721], Some(514579))
+        );
+        let triple = k_sum(&EXAMPLE, 3, 2020).unwrap();
+        assert_eq!(triple.entries, [366, 675, 979]);
+        assert_eq!(triple.product, Some(241861950));
+        assert_eq!(k_sum(&EXAMPLE, 2, 1000), None);
+        let report = EXAMPLE.to_vec();
+        assert_eq!(Day1::part1(&report), Product::Found(514579));
+        assert_eq!(Day1::part2(&report), Product::Found(241861950));
+    }
+
+    #[test]
+    fn no_match() {
+        let report = vec![1, 2];
+        assert_eq!(Day1::part1(&report), Product::NoMatch);
+        assert_eq!(Day1::part2(&report).to_string(), "no entries sum to 2020");
+    }
+
+    #[test]
+    fn entries_are_used_once() {
+        assert_eq!(k_sum(&[1010, 5], 2, 2020), None);
+        assert_eq!(
+            k_sum(&[1010, 5, 1010], 2, 2020).unwrap().entries,
+            [1010, 1010]
+        );
+        assert_eq!(k_sum(&[1, 1, 1, 2], 4, 5).unwrap().product, Some(2));
+    }
+
+    #[test]
+    fn every_match() {
+        let report = [1010, 5, 1010, 2015, 1010];
+        let lines: Vec<_> = k_sums(&report, 2, 2020).map(|found| found.lines).collect();
+        assert_eq!(lines, [vec![2, 4], vec![1, 3], vec![1, 5], vec![3, 5]]);
+        assert_eq!(k_sums(&[1, 1, 1, 1], 3, 3).count(), 4);
+        assert_eq!(k_sums(&EXAMPLE, 1, 979).count(), 1);
+        assert_eq!(k_sums(&EXAMPLE, 3, 1).count(), 0);
+    }
+
+    #[test]
+    fn large_report() {
+        let report: Vec<i32> = (0..100_000).map(|i| i * 7 + 3).collect();
+        let found = k_sum(&report, 3, 699_982 + 699_989 + 699_996).unwrap();
+        assert_eq!(found.entries, [699_982, 699_989, 699_996]);
+        assert_eq!(k_sum(&report, 4, 1), None);
+    }
+
+    #[test]
+    fn large_products() {
+        let billion = 1_000_000_000;
+        let found = k_sum(&[billion, billion, billion], 3, 3 * billion as i64).unwrap();
+        assert_eq!(found.product, Some(10i128.pow(27)));
+        let report = [i32::MAX; 5];
+        let found = k_sum(&report, 5, 5 * i32::MAX as i64).unwrap();
+        assert_eq!(found.product, None);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::grid::ADJACENT;
+use crate::{Grid, ParseError, Solution};
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Chair {
+    FLOOR,
+    OPEN,
+    OCCUPIED,
+}
+
+impl Chair {
+    fn from_char(c: char) -> Option<Chair> {
+        match c {
+            '.' => Some(Chair::FLOOR),
+            'L' => Some(Chair::OPEN),
+            '#' => Some(Chair::OCCUPIED),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Chair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Chair::FLOOR => ".",
+            Chair::OPEN => "L",
+            Chair::OCCUPIED => "#",
+        })
+    }
+}
+
+impl fmt::Debug for Chair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[derive(Clone)]
+pub struct Area {
+    pub seats: Grid<Chair>,
+}
+
+impl fmt::Debug for Area {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.seats, f)
+    }
+}
+
+impl Area {
+    pub fn step(&mut self, is_part2: bool) -> usize {
+        let mut changes = 0;
+        let limit = if is_part2 { 5 } else { 4 };
+        let seats = &self.seats;
+        let new_seats = Grid::from_fn(seats.width(), seats.height(), |x, y| {
+            let seat = seats[(x, y)];
+            if seat == Chair::FLOOR {
+                return seat;
+            }
+            let neighbors = if is_part2 {
+                self.count_visible(x, y)
+            } else {
+                self.count_neighbors(x, y)
+            };
+            match neighbors {
+                0 => {
+                    changes += (seat == Chair::OPEN) as usize;
+                    Chair::OCCUPIED
+                }
+                count if count < limit => seat,
+                _ => {
+                    changes += (seat == Chair::OCCUPIED) as usize;
+                    Chair::OPEN
+                }
+            }
+        });
+        self.seats = new_seats;
+        changes
+    }
+
+    fn count_neighbors(&self, x: usize, y: usize) -> usize {
+        self.seats
+            .neighbors8(x, y)
+            .filter(|&position| self.seats[position] == Chair::OCCUPIED)
+            .count()
+    }
+
+    /// Counts occupied seats seen looking past the floor in each of the eight directions.
+    fn count_visible(&self, x: usize, y: usize) -> usize {
+        ADJACENT
+            .iter()
+            .filter(|&&direction| {
+                self.seats
+                    .ray(x, y, direction)
+                    .map(|position| self.seats[position])
+                    .find(|&seat| seat != Chair::FLOOR)
+                    == Some(Chair::OCCUPIED)
+            })
+            .count()
+    }
+
+    pub fn count_occupied(&self) -> usize {
+        self.seats
+            .iter()
+            .filter(|&&seat| seat == Chair::OCCUPIED)
+            .count()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Area;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Area, ParseError> {
+        let seats = Grid::parse(input, Chair::from_char, "expected one of '.', 'L' or '#'")?;
+        Ok(Area { seats })
+    }
+
+    fn part1(seats: &Area) -> usize {
+        let mut seats = seats.clone();
+        while seats.step(false) != 0 {}
+        seats.count_occupied()
+    }
+
+    fn part2(seats: &Area) -> usize {
+        let mut seats = seats.clone();
+        while seats.step(true) != 0 {}
+        seats.count_occupied()
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{parse_at, ParseError, Solution};
+use std::fmt;
+
+trait ShipShape {
+    fn x(&self) -> i32;
+    fn y(&self) -> i32;
+    fn perform(&mut self, action: &Action);
+}
+
+/// A navigation instruction, already checked to be one the ship understands.
+#[derive(Clone, Copy, Debug)]
+pub struct Action {
+    pub kind: char,
+    pub value: i32,
+}
+
+impl Action {
+    fn parse(input: &str, line: &str) -> Result<Action, ParseError> {
+        let kind = match line.chars().next() {
+            Some(kind) if "NESWLRF".contains(kind) => kind,
+            _ => {
+                let end = line.chars().next().map_or(0, char::len_utf8);
+                return Err(ParseError::at(
+                    input,
+                    &line[..end],
+                    "expected one of 'N', 'E', 'S', 'W', 'L', 'R' or 'F'",
+                ));
+            }
+        };
+        let value = parse_at(input, &line[1..])?;
+        if (kind == 'L' || kind == 'R') && value % 90 != 0 {
+            return Err(ParseError::at(
+                input,
+                &line[1..],
+                "turns must be a multiple of 90 degrees",
+            ));
+        }
+        Ok(Action { kind, value })
+    }
+}
+
+#[derive(Debug)]
+struct Ship {
+    pub x: i32,
+    pub y: i32,
+    pub bearing: i32,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            x: 0,
+            y: 0,
+            bearing: 90,
+        }
+    }
+}
+
+impl ShipShape for Ship {
+    fn x(&self) -> i32 {
+        self.x
+    }
+    fn y(&self) -> i32 {
+        self.y
+    }
+    fn perform(&mut self, action: &Action) {
+        let action_value = &action.value;
+        match action.kind {
+            'N' => self.y += action_value,
+            'E' => self.x += action_value,
+            'S' => self.y -= action_value,
+            'W' => self.x -= action_value,
+            'L' => self.bearing = (self.bearing - action_value).rem_euclid(360),
+            'R' => self.bearing = (self.bearing + action_value).rem_euclid(360),
+            'F' => match self.bearing {
+                0 => self.y += action_value,
+                90 => self.x += action_value,
+                180 => self.y -= action_value,
+                270 => self.x -= action_value,
+                bearing => panic!("Unexpected bearing {}", bearing),
+            },
+            _ => unreachable!("unexpected action {:?}", action),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Ship2 {
+    x: i32,
+    y: i32,
+    way_x: i32,
+    way_y: i32,
+}
+
+impl Ship2 {
+    pub fn new() -> Ship2 {
+        Ship2 {
+            x: 0,
+            y: 0,
+            way_x: 10,
+            way_y: 1,
+        }
+    }
+}
+
+impl ShipShape for Ship2 {
+    fn x(&self) -> i32 {
+        self.x
+    }
+    fn y(&self) -> i32 {
+        self.y
+    }
+    fn perform(&mut self, action: &Action) {
+        let action_value = &action.value;
+        match action.kind {
+            'N' => self.way_y += action_value,
+            'E' => self.way_x += action_value,
+            'S' => self.way_y -= action_value,
+            'W' => self.way_x -= action_value,
+            'L' => {
+                for _ in 0..(action_value / 90) {
+                    let way_xy = (-self.way_y, self.way_x);
+                    self.way_x = way_xy.0;
+                    self.way_y = way_xy.1;
+                }
+            }
+            'R' => {
+                for _ in 0..(action_value / 90) {
+                    let way_xy = (self.way_y, -self.way_x);
+                    self.way_x = way_xy.0;
+                    self.way_y = way_xy.1;
+                }
+            }
+            'F' => {
+                self.x += self.way_x * action_value;
+                self.y += self.way_y * action_value;
+            }
+            _ => unreachable!("unexpected action {:?}", action),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Action>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Action>, ParseError> {
+        input
+            .lines()
+            .map(|line| Action::parse(input, line))
+            .collect()
+    }
+
+    fn part1(actions: &Vec<Action>) -> i32 {
+        run_actions(&mut Ship::new(), actions)
+    }
+
+    fn part2(actions: &Vec<Action>) -> i32 {
+        run_actions(&mut Ship2::new(), actions)
+    }
+}
+
+fn run_actions(ship: &mut (impl ShipShape + fmt::Debug), actions: &[Action]) -> i32 {
+    actions.iter().for_each(|action| ship.perform(action));
+    ship.x().abs() + ship.y().abs()
+}
@@ -0,0 +1,131 @@
+use crate::{parse_at, ParseError, Solution};
+use modinverse::modinverse;
+use num::integer::gcd;
+use std::collections::HashMap;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (i64, Vec<Option<i64>>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<(i64, Vec<Option<i64>>), ParseError> {
+        let end = &input[input.len()..];
+        let mut lines = input.lines();
+        let earliest_time = parse_at(input, lines.next().unwrap_or(end))?;
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::at(input, end, "expected a line of bus ids"))?;
+        // Part 2 relies on the Chinese remainder theorem, so the ids must be pairwise coprime.
+        let mut buses: Vec<i64> = Vec::new();
+        let ids = line
+            .split(',')
+            .map(|s| {
+                if s == "x" {
+                    return Ok(None);
+                }
+                let id = parse_at(input, s)?;
+                if id <= 0 {
+                    return Err(ParseError::at(input, s, "expected a positive bus id"));
+                }
+                if let Some(other) = buses.iter().find(|&&other| gcd(id, other) != 1) {
+                    return Err(ParseError::at(
+                        input,
+                        s,
+                        format!(
+                            "bus ids must be coprime, but this shares a factor with {}",
+                            other
+                        ),
+                    ));
+                }
+                buses.push(id);
+                Ok(Some(id))
+            })
+            .collect::<Result<_, _>>()?;
+        if buses.is_empty() {
+            return Err(ParseError::at(input, line, "expected at least one bus id"));
+        }
+        Ok((earliest_time, ids))
+    }
+
+    fn part1((earliest_time, ids): &(i64, Vec<Option<i64>>)) -> i64 {
+        earliest_bus(*earliest_time, ids)
+    }
+
+    fn part2((_, ids): &(i64, Vec<Option<i64>>)) -> i64 {
+        earliest_sequence(ids)
+    }
+}
+
+fn earliest_bus(earliest_time: i64, ids: &[Option<i64>]) -> i64 {
+    ids.iter()
+        .filter_map(|x| x.as_ref())
+        .map(|x| {
+            let waiting_time = x - (earliest_time % x);
+            (waiting_time, waiting_time * x)
+        })
+        .min()
+        .unwrap()
+        .1
+}
+
+fn earliest_sequence(ids: &[Option<i64>]) -> i64 {
+    let bases: Vec<_> = ids.iter().filter_map(|x| *x).collect();
+    let modulus: i64 = bases.iter().product();
+    let first = *bases.first().unwrap();
+    let mults: HashMap<i64, i64> = bases
+        .iter()
+        .map(|base| {
+            (
+                *base,
+                bases.iter().fold(1, |acc, sub_base| {
+                    if sub_base == base {
+                        acc
+                    } else {
+                        (acc * sub_base % modulus) * modinverse(*sub_base, *base).unwrap() % modulus
+                    }
+                }),
+            )
+        })
+        .collect();
+
+    ids.iter()
+        .enumerate()
+        .filter_map(|(i, x)| match x {
+            Some(val) if *val != first => Some((i as i64, *val)),
+            _ => None,
+        })
+        .map(|(i, x)| {
+            let inverse = modinverse(first, x).unwrap();
+            (inverse * -i).rem_euclid(x) * mults[&x]
+        })
+        .sum::<i64>()
+        * first
+        % modulus
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day13;
+    use crate::Solution;
+
+    #[test]
+    fn example() {
+        let notes = Day13::parse("939\n7,13,x,x,59,x,31,19\n").unwrap();
+        assert_eq!(Day13::part1(&notes), 295);
+        assert_eq!(Day13::part2(&notes), 1068781);
+        let notes = Day13::parse("10\n7,x\n").unwrap();
+        assert_eq!(Day13::part1(&notes), 28);
+        assert_eq!(Day13::part2(&notes), 0);
+    }
+
+    #[test]
+    fn reports_unusable_bus_ids() {
+        let column = |input| Day13::parse(input).unwrap_err().column;
+        assert_eq!(column("939\n7,0"), 3);
+        assert_eq!(column("939\n7,-13"), 3);
+        assert_eq!(column("939\n6,x,7,9"), 7);
+        assert_eq!(column("939\nx,x"), 1);
+    }
+}
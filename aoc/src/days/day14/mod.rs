@@ -1,3 +1,4 @@
+use crate::{parse_at, ParseError, Solution};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -79,11 +80,15 @@ impl MaskLike for MaskV2 {
     }
 }
 
+#[derive(Clone, Debug)]
+pub enum Command {
+    Mask(String),
+    Write { address: u64, value: u64 },
+}
+
 struct Port {
     mask: Box<dyn MaskLike>,
     mem: HashMap<u64, u64>,
-    mask_regex: Regex,
-    mem_regex: Regex,
     version: u8,
 }
 
@@ -92,8 +97,6 @@ impl Port {
         Port {
             mask: Box::new(Mask::from_string("")),
             mem: HashMap::new(),
-            mask_regex: Regex::new(r"mask = ([01X]{36})").unwrap(),
-            mem_regex: Regex::new(r"mem\[(\d*)\] = (\d*)").unwrap(),
             version: 0,
         }
     }
@@ -105,56 +108,75 @@ impl Port {
         port
     }
 
-    pub fn perform(&mut self, command: &str) {
-        if let Some(mask_caps) = self.mask_regex.captures(command) {
-            self.mask = match self.version {
-                0 => Box::new(Mask::from_string(&mask_caps[1])),
-                1 => Box::new(MaskV2::from_string(&mask_caps[1])),
-                _ => panic!("Unexpected version"),
+    pub fn perform(&mut self, command: &Command) {
+        match command {
+            Command::Mask(mask) => {
+                self.mask = match self.version {
+                    0 => Box::new(Mask::from_string(mask)),
+                    1 => Box::new(MaskV2::from_string(mask)),
+                    _ => panic!("Unexpected version"),
+                }
             }
-        } else if let Some(mem_caps) = self.mem_regex.captures(command) {
-            match self.version {
+            &Command::Write { address, value } => match self.version {
                 0 => {
-                    self.mem.insert(
-                        mem_caps[1].parse().unwrap(),
-                        self.mask.apply_to(mem_caps[2].parse().unwrap())[0],
-                    );
+                    self.mem.insert(address, self.mask.apply_to(value)[0]);
                 }
                 1 => {
-                    let value = mem_caps[2].parse().unwrap();
-                    self.mask
-                        .apply_to(mem_caps[1].parse().unwrap())
-                        .iter()
-                        .for_each(|addr| {
-                            self.mem.insert(*addr, value);
-                        });
+                    self.mask.apply_to(address).iter().for_each(|addr| {
+                        self.mem.insert(*addr, value);
+                    });
                 }
                 _ => panic!("Unexpected version"),
-            }
-        } else {
-            panic!("Unexpeted command format: {}", command);
+            },
         }
     }
 
     pub fn sum_mem(&self) -> u64 {
-        self.mem.values().fold(0, |acc, val| acc + val)
+        self.mem.values().sum()
     }
 }
 
-fn main() {
-    let commands: Vec<_> = include_str!("input.txt").lines().collect();
-    println!("part1: {}", part1(&commands));
-    println!("part2: {}", part2(&commands));
-}
+pub struct Day14;
 
-fn part1(commands: &Vec<&str>) -> u64 {
-    let mut port = Port::new();
-    commands.iter().for_each(|command| port.perform(command));
-    port.sum_mem()
-}
+impl Solution for Day14 {
+    type Input = Vec<Command>;
+    type Part1 = u64;
+    type Part2 = u64;
 
-fn part2(commands: &Vec<&str>) -> u64 {
-    let mut port = Port::new_v2();
-    commands.iter().for_each(|command| port.perform(command));
-    port.sum_mem()
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        let mask_regex = Regex::new(r"^mask = ([01X]{36})$").unwrap();
+        let mem_regex = Regex::new(r"^mem\[(\d+)\] = (\d+)$").unwrap();
+        input
+            .lines()
+            .map(|line| {
+                if let Some(mask_caps) = mask_regex.captures(line) {
+                    Ok(Command::Mask(mask_caps[1].to_string()))
+                } else if let Some(mem_caps) = mem_regex.captures(line) {
+                    let field = |i| &line[mem_caps.get(i).unwrap().range()];
+                    Ok(Command::Write {
+                        address: parse_at(input, field(1))?,
+                        value: parse_at(input, field(2))?,
+                    })
+                } else {
+                    Err(ParseError::at(
+                        input,
+                        line,
+                        "expected \"mask = <36 bits>\" or \"mem[<address>] = <value>\"",
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(commands: &Vec<Command>) -> u64 {
+        let mut port = Port::new();
+        commands.iter().for_each(|command| port.perform(command));
+        port.sum_mem()
+    }
+
+    fn part2(commands: &Vec<Command>) -> u64 {
+        let mut port = Port::new_v2();
+        commands.iter().for_each(|command| port.perform(command));
+        port.sum_mem()
+    }
 }
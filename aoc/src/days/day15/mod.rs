@@ -1,20 +1,31 @@
+use crate::{parse_at, ParseError, Part, Solution};
 use std::collections::HashMap;
 
-enum Part {
-    One,
-    Two,
-}
+pub struct Day15;
 
-fn main() {
-    let starting_numbers = include_str!("input.txt")
-        .split(",")
-        .map(|s| s.parse().unwrap())
-        .collect();
-    println!("part1: {}", solve(&starting_numbers, Part::One));
-    println!("part2: {}", solve(&starting_numbers, Part::Two));
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        input
+            .trim()
+            .split(",")
+            .map(|s| parse_at(input, s))
+            .collect()
+    }
+
+    fn part1(starting_numbers: &Vec<usize>) -> usize {
+        solve(starting_numbers, Part::One)
+    }
+
+    fn part2(starting_numbers: &Vec<usize>) -> usize {
+        solve(starting_numbers, Part::Two)
+    }
 }
 
-fn solve(starting_numbers: &Vec<usize>, part: Part) -> usize {
+fn solve(starting_numbers: &[usize], part: Part) -> usize {
     let mut history: HashMap<_, _> = starting_numbers[..starting_numbers.len() - 1]
         .iter()
         .enumerate()
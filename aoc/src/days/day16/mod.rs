@@ -1,35 +1,28 @@
+use crate::{parse_at, ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::assert;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-struct Field {
+pub struct Field {
     pub name: String,
     ranges: Vec<(usize, usize)>,
 }
 
 impl Field {
-    pub fn from_str(field_str: &str) -> Field {
+    pub fn from_str(input: &str, field_str: &str) -> Result<Field, ParseError> {
         lazy_static! {
-            static ref FIELD_PARSER: Regex = Regex::new(r"(.*?): (\d*-\d*) or (\d*-\d*)").unwrap();
+            static ref FIELD_PARSER: Regex =
+                Regex::new(r"^(.*?): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
         }
-        let caps = FIELD_PARSER.captures(field_str).unwrap();
+        let caps = FIELD_PARSER.captures(field_str).ok_or_else(|| {
+            ParseError::at(input, field_str, "expected \"<name>: <a>-<b> or <c>-<d>\"")
+        })?;
+        let boundary = |i| parse_at(input, &field_str[caps.get(i).unwrap().range()]);
 
         let name = caps[1].to_string();
-        let ranges = (2..=3)
-            .map(|i| {
-                let min_max: Vec<_> = caps[i]
-                    .split('-')
-                    .map(|boundary| boundary.parse().unwrap())
-                    .collect();
-                (min_max[0], min_max[1])
-            })
-            .collect();
-        Field {
-            name: name,
-            ranges: ranges,
-        }
+        let ranges = vec![(boundary(2)?, boundary(3)?), (boundary(4)?, boundary(5)?)];
+        Ok(Field { name, ranges })
     }
 
     pub fn validate(&self, val: usize) -> bool {
@@ -39,44 +32,64 @@ impl Field {
     }
 }
 
-fn main() {
-    let mut input_iter = include_str!("input.txt").lines();
+pub struct Notes {
+    fields: Vec<Field>,
+    my_ticket: Vec<usize>,
+    tickets: Vec<Vec<usize>>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        let mut input_iter = input.lines();
 
-    let mut fields: Vec<Field> = Vec::new();
-    while let Some(line) = input_iter.next() {
-        if line == "" {
-            break;
+        let mut fields: Vec<Field> = Vec::new();
+        for line in input_iter.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            fields.push(Field::from_str(input, line)?);
         }
 
-        fields.push(Field::from_str(line));
-    }
+        expect_line(input, input_iter.next(), "your ticket:")?;
 
-    assert!(input_iter.next().unwrap() == "your ticket:");
+        let my_ticket = parse_ticket(input, input_iter.next())?;
 
-    let my_ticket: Vec<usize> = input_iter
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect();
+        expect_line(input, input_iter.next(), "")?;
+        expect_line(input, input_iter.next(), "nearby tickets:")?;
 
-    assert!(input_iter.next().unwrap() == "");
-    assert!(input_iter.next().unwrap() == "nearby tickets:");
+        let mut tickets: Vec<Vec<usize>> = Vec::new();
+        for line in input_iter.by_ref() {
+            if line.is_empty() {
+                break;
+            }
 
-    let mut tickets: Vec<Vec<usize>> = Vec::new();
-    while let Some(line) = input_iter.next() {
-        if line == "" {
-            break;
+            tickets.push(parse_ticket(input, Some(line))?);
         }
 
-        tickets.push(line.split(',').map(|s| s.parse().unwrap()).collect());
+        Ok(Notes {
+            fields,
+            my_ticket,
+            tickets,
+        })
+    }
+
+    fn part1(notes: &Notes) -> usize {
+        error_rate(&notes.fields, &notes.tickets)
     }
 
-    println!("part1: {}", part1(&fields, &tickets));
-    println!("part2: {}", part2(&fields, &tickets, &my_ticket));
+    fn part2(notes: &Notes) -> usize {
+        departure_product(&notes.fields, &notes.tickets, &notes.my_ticket)
+    }
 }
 
-fn part1(fields: &Vec<Field>, tickets: &Vec<Vec<usize>>) -> usize {
+fn error_rate(fields: &[Field], tickets: &[Vec<usize>]) -> usize {
     tickets
         .iter()
         .map(|ticket| {
@@ -91,7 +104,7 @@ fn part1(fields: &Vec<Field>, tickets: &Vec<Vec<usize>>) -> usize {
         .sum()
 }
 
-fn part2(fields: &Vec<Field>, tickets: &Vec<Vec<usize>>, my_ticket: &Vec<usize>) -> usize {
+fn departure_product(fields: &[Field], tickets: &[Vec<usize>], my_ticket: &[usize]) -> usize {
     let valid_tickets: Vec<_> = tickets
         .iter()
         .filter(|ticket| {
@@ -153,3 +166,21 @@ fn part2(fields: &Vec<Field>, tickets: &Vec<Vec<usize>>, my_ticket: &Vec<usize>)
             }
         })
 }
+
+fn expect_line(input: &str, line: Option<&str>, expected: &str) -> Result<(), ParseError> {
+    match line {
+        Some(line) if line == expected => Ok(()),
+        line => Err(ParseError::at(
+            input,
+            line.unwrap_or(&input[input.len()..]),
+            format!("expected {:?}", expected),
+        )),
+    }
+}
+
+fn parse_ticket(input: &str, line: Option<&str>) -> Result<Vec<usize>, ParseError> {
+    line.unwrap_or(&input[input.len()..])
+        .split(',')
+        .map(|s| parse_at(input, s))
+        .collect()
+}
@@ -0,0 +1,39 @@
+use crate::life::{Life, Neighborhood, Rule};
+use crate::{Grid, ParseError, Solution};
+
+const CYCLES: usize = 6;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse(
+            input,
+            |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "expected '#' or '.'",
+        )
+    }
+
+    fn part1(initial_cubes: &Grid<bool>) -> usize {
+        boot(initial_cubes, 3)
+    }
+
+    fn part2(initial_cubes: &Grid<bool>) -> usize {
+        boot(initial_cubes, 4)
+    }
+}
+
+fn boot(initial_cubes: &Grid<bool>, dimensions: usize) -> usize {
+    let mut pocket = Life::new(Neighborhood::Cubic(dimensions), Rule::new(&[3], &[2, 3]));
+    pocket.plant(initial_cubes);
+    pocket.run(CYCLES);
+    pocket.population()
+}
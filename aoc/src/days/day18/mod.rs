@@ -0,0 +1,170 @@
+use crate::{ParseError, Solution};
+use std::str::Chars;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Plus,
+    Times,
+}
+
+impl Operator {
+    fn apply(&self, left: u64, right: u64) -> u64 {
+        match self {
+            Operator::Plus => left + right,
+            Operator::Times => left * right,
+        }
+    }
+}
+
+fn eval(input: &mut Chars, low_prec: Option<Operator>) -> u64 {
+    let mut value = 0;
+    let mut op = Operator::Plus;
+
+    let mut delayed_terms: Vec<u64> = Vec::new();
+
+    while let Some(c) = input.next() {
+        match c {
+            ' ' => {}
+            '+' => op = Operator::Plus,
+            '*' => op = Operator::Times,
+            '(' => {
+                value = match low_prec {
+                    Some(low_prec_op) if low_prec_op == op => {
+                        delayed_terms.push(value);
+                        eval(input, low_prec)
+                    }
+                    _ => op.apply(value, eval(input, low_prec)),
+                }
+            }
+            ')' => break,
+            digit => {
+                value = match low_prec {
+                    Some(low_prec_op) if low_prec_op == op => {
+                        delayed_terms.push(value);
+                        digit.to_digit(10).unwrap() as u64
+                    }
+                    _ => op.apply(value, digit.to_digit(10).unwrap() as u64),
+                }
+            }
+        }
+    }
+
+    if let Some(low_prec_op) = low_prec {
+        delayed_terms
+            .iter()
+            .fold(value, |acc, term| low_prec_op.apply(acc, *term))
+    } else {
+        value
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| check_expression(input, line).map(|()| line.to_string()))
+            .collect()
+    }
+
+    fn part1(expressions: &Vec<String>) -> u64 {
+        expressions
+            .iter()
+            .map(|expression| eval(&mut expression.chars(), None))
+            .sum()
+    }
+
+    fn part2(expressions: &Vec<String>) -> u64 {
+        expressions
+            .iter()
+            .map(|expression| eval(&mut expression.chars(), Some(Operator::Times)))
+            .sum()
+    }
+}
+
+/// Makes sure `eval` will only see single digits and operators between them, with balanced
+/// parentheses.
+fn check_expression(input: &str, line: &str) -> Result<(), ParseError> {
+    let mut open = Vec::new();
+    // Whether the last thing seen was a whole operand, so an operator or ')' comes next.
+    let mut after_operand = false;
+    let mut last = ' ';
+    for (i, c) in line.char_indices() {
+        let at = &line[i..i + c.len_utf8()];
+        match (c, after_operand) {
+            (' ', _) => {}
+            ('0'..='9', false) => after_operand = true,
+            ('(', false) => open.push(at),
+            ('+', true) | ('*', true) => after_operand = false,
+            (')', true) => {
+                if open.pop().is_none() {
+                    return Err(ParseError::at(input, at, "unmatched ')'"));
+                }
+            }
+            ('0'..='9', true) if last.is_ascii_digit() => {
+                return Err(ParseError::at(input, at, "numbers must be a single digit"))
+            }
+            ('0'..='9', true) | ('(', true) | (')', false) | ('+', false) | ('*', false) => {
+                let expected = if after_operand {
+                    "expected '+', '*' or ')'"
+                } else {
+                    "expected a digit or '('"
+                };
+                return Err(ParseError::at(input, at, expected));
+            }
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    at,
+                    "expected a digit, '+', '*' or a parenthesis",
+                ))
+            }
+        }
+        last = c;
+    }
+    if !after_operand {
+        return Err(ParseError::at(
+            input,
+            &line[line.len()..],
+            "expected a digit or '('",
+        ));
+    }
+    match open.pop() {
+        Some(at) => Err(ParseError::at(input, at, "unmatched '('")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day18;
+    use crate::Solution;
+
+    #[test]
+    fn examples() {
+        let expressions = Day18::parse("2 * 3 + (4 * 5)\n5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap();
+        assert_eq!(Day18::part1(&expressions), 26 + 437);
+        assert_eq!(Day18::part2(&expressions), 46 + 1445);
+    }
+
+    #[test]
+    fn reports_malformed_expressions() {
+        let column = |line| Day18::parse(line).unwrap_err().column;
+        assert_eq!(column("12 + 3"), 2);
+        assert_eq!(column("+"), 1);
+        assert_eq!(column("1 +"), 4);
+        assert_eq!(column("1 2"), 3);
+        assert_eq!(column("1 + * 2"), 5);
+        assert_eq!(column("(1 + 2))"), 8);
+        assert_eq!(column("((1 + 2)"), 1);
+        assert_eq!(column("()"), 2);
+        assert_eq!(column("\n  1 + 2\n  3 +"), 6);
+        assert_eq!(column("1 - 2"), 3);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{parse_at, ParseError, Solution};
+use alphanumeric_sort;
+use regex::Regex;
+
+pub enum RulePart {
+    BAR,
+    REF(usize),
+    LETTER(char),
+}
+
+impl RulePart {
+    pub fn from_str(input: &str, from: &str, rule_count: usize) -> Result<RulePart, ParseError> {
+        match (from, from.parse::<usize>()) {
+            (_, Ok(x)) if x < rule_count => Ok(RulePart::REF(x)),
+            (_, Ok(_)) => Err(ParseError::at(
+                input,
+                from,
+                "reference to an undefined rule",
+            )),
+            ("|", _) => Ok(RulePart::BAR),
+            (letter, _) => {
+                let mut chars = letter.chars();
+                match (chars.next(), chars.next(), chars.next(), chars.next()) {
+                    (Some('"'), Some(letter), Some('"'), None) => Ok(RulePart::LETTER(letter)),
+                    _ => Err(ParseError::at(
+                        input,
+                        from,
+                        "expected a rule number, '|' or a quoted letter",
+                    )),
+                }
+            }
+        }
+    }
+}
+
+fn parse_rule(
+    input: &str,
+    rule: &str,
+    rule_count: usize,
+) -> Result<(usize, Vec<RulePart>), ParseError> {
+    let mut labelling = rule.splitn(2, ":");
+    let rule_number = parse_at(input, labelling.next().unwrap())?;
+    let sub_rules = labelling
+        .next()
+        .ok_or_else(|| ParseError::at(input, rule, "expected \"<number>: <rule>\""))?
+        .split_whitespace()
+        .map(|s| RulePart::from_str(input, s, rule_count))
+        .collect::<Result<_, _>>()?;
+    Ok((rule_number, sub_rules))
+}
+
+fn regex_from_rules(rules: &Vec<Vec<RulePart>>, is_part2: bool) -> Regex {
+    Regex::new(&format!(
+        "^(?:{})$",
+        regex_str_from_rule(0, rules, is_part2)
+    ))
+    .unwrap()
+}
+
+fn regex_str_from_rule(rule: usize, rules: &Vec<Vec<RulePart>>, is_part2: bool) -> String {
+    match (is_part2, rule) {
+        (true, 8) => format!("(?:{})+", regex_str_from_rule(42, rules, is_part2)),
+        (true, 11) => {
+            let left = regex_str_from_rule(42, rules, is_part2);
+            let right = regex_str_from_rule(31, rules, is_part2);
+            format!(
+                "(?:{})",
+                (1..=5)
+                    .map(|i| format!("(?:{}){{{}}}(?:{}){{{}}}", left, i, right, i))
+                    .collect::<Vec<_>>()
+                    .join("|")
+            )
+        }
+        _ => {
+            let mut result = "".to_owned();
+            let mut should_bracket_end = false;
+            for part in &rules[rule] {
+                match part {
+                    RulePart::LETTER(letter) => result.push(*letter),
+                    RulePart::REF(sub_rule) => {
+                        result.push_str(&regex_str_from_rule(*sub_rule, rules, is_part2))
+                    }
+                    RulePart::BAR => {
+                        result = format!("(?:{}|", result);
+                        should_bracket_end = true;
+                    }
+                }
+            }
+            if should_bracket_end {
+                result.push(')');
+            }
+            result
+        }
+    }
+}
+
+pub struct Messages {
+    rules: Vec<Vec<RulePart>>,
+    patterns: Vec<String>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Messages;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Messages, ParseError> {
+        let mut rule_lines = Vec::new();
+        let mut input_iter = input.lines();
+        for line in input_iter.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            rule_lines.push(line);
+        }
+        alphanumeric_sort::sort_str_slice(&mut rule_lines);
+
+        let rules: Vec<Vec<RulePart>> = rule_lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let parsed_rule = parse_rule(input, line, rule_lines.len())?;
+                if parsed_rule.0 != i {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("rules must be numbered 0 to {}", rule_lines.len() - 1),
+                    ));
+                }
+                Ok(parsed_rule.1)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let patterns = input_iter.map(|s| s.to_string()).collect();
+
+        Ok(Messages { rules, patterns })
+    }
+
+    fn part1(messages: &Messages) -> usize {
+        solve(
+            &messages.patterns,
+            &regex_from_rules(&messages.rules, false),
+        )
+    }
+
+    fn part2(messages: &Messages) -> usize {
+        solve(&messages.patterns, &regex_from_rules(&messages.rules, true))
+    }
+}
+
+fn solve(patterns: &[String], checker: &Regex) -> usize {
+    patterns
+        .iter()
+        .filter(|pattern| checker.is_match(pattern))
+        .count()
+}
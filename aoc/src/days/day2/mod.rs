@@ -0,0 +1,160 @@
+use crate::{parse_at, ParseError, Solution};
+use std::str::FromStr;
+
+mod policy;
+
+pub use policy::{
+    parse_policies, parse_policy, AtLeast, AtMost, Between, Class, ContainsOneOf, ExactlyOneAt,
+    NoRunsLongerThan, Policy,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<PasswordEntry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse::<PasswordEntry>()
+                    .map_err(|err| err.within(input, line))
+            })
+            .collect()
+    }
+
+    fn part1(entries: &Self::Input) -> usize {
+        entries
+            .iter()
+            .filter(|entry| entry.sled_policy().allows(&entry.password))
+            .count()
+    }
+
+    fn part2(entries: &Self::Input) -> usize {
+        entries
+            .iter()
+            .filter(|entry| entry.toboggan_policy().allows(&entry.password))
+            .count()
+    }
+}
+
+/// A line of the password database: `<first>-<second> <letter>: <password>`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PasswordEntry {
+    pub first: usize,
+    pub second: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+impl PasswordEntry {
+    /// The numbers as the fewest and most times the letter may appear.
+    pub fn sled_policy(&self) -> Between {
+        Between {
+            min: self.first,
+            max: self.second,
+            class: Class::Char(self.letter),
+        }
+    }
+
+    /// The numbers as positions, exactly one of which must hold the letter.
+    pub fn toboggan_policy(&self) -> ExactlyOneAt {
+        ExactlyOneAt {
+            positions: (self.first, self.second),
+            class: Class::Char(self.letter),
+        }
+    }
+}
+
+/// Errors are positioned within the line alone.
+impl FromStr for PasswordEntry {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<PasswordEntry, ParseError> {
+        let (numbers, rest) = line.split_once(' ').ok_or_else(|| {
+            ParseError::at(
+                line,
+                line,
+                "expected \"<first>-<second> <letter>: <password>\"",
+            )
+        })?;
+        let (first, second) = numbers
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(line, numbers, "expected \"<first>-<second>\""))?;
+        let (first, second) = (parse_at(line, first)?, parse_at(line, second)?);
+        let (letter, password) = rest
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(line, rest, "expected \"<letter>: <password>\""))?;
+        let mut letters = letter.chars();
+        let letter = match (letters.next(), letters.next()) {
+            (Some(c), None) => c,
+            _ => return Err(ParseError::at(line, letter, "expected a single letter")),
+        };
+        if password.is_empty() || password.contains(char::is_whitespace) {
+            return Err(ParseError::at(
+                line,
+                password,
+                "expected a password without spaces",
+            ));
+        }
+        Ok(PasswordEntry {
+            first,
+            second,
+            letter,
+            password: password.to_string(),
+        })
+    }
+}
+
+/// The policies among `policies` that `password` breaks.
+pub fn violations<'a>(policies: &'a [Box<dyn Policy>], password: &str) -> Vec<&'a dyn Policy> {
+    policies
+        .iter()
+        .map(|policy| policy.as_ref())
+        .filter(|policy| !policy.allows(password))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day2, PasswordEntry, Policy};
+    use crate::Solution;
+
+    #[test]
+    fn parses_entries() {
+        let entry: PasswordEntry = "1-3 a: abcde".parse().unwrap();
+        assert_eq!(
+            entry,
+            PasswordEntry {
+                first: 1,
+                second: 3,
+                letter: 'a',
+                password: "abcde".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn reports_the_wrong_column() {
+        let column = |line: &str| line.parse::<PasswordEntry>().unwrap_err().column;
+        assert_eq!(column("1-3a: abcde"), 3);
+        assert_eq!(column("13 a: abcde"), 1);
+        assert_eq!(column("1-x a: abcde"), 3);
+        assert_eq!(column("1-3 ab: abcde"), 5);
+        assert_eq!(column("1-3 a abcde"), 5);
+        assert_eq!(column("1-3 a: "), 8);
+        let error = Day2::parse("1-3 a: abcde\n2-9 c:ccccccccc").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+
+    #[test]
+    fn positions_past_the_end_are_invalid() {
+        let entry: PasswordEntry = "1-9 a: abc".parse().unwrap();
+        assert!(!entry.toboggan_policy().allows(&entry.password));
+        let entry: PasswordEntry = "0-2 b: abc".parse().unwrap();
+        assert!(!entry.toboggan_policy().allows(&entry.password));
+        assert!(entry.sled_policy().allows(&entry.password));
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{parse_at, ParseError};
+use std::fmt;
+
+/// A requirement on passwords, displayed in the rule syntax it's parsed from.
+pub trait Policy: fmt::Display {
+    fn allows(&self, password: &str) -> bool;
+}
+
+/// Some kind of character a rule can count.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Class {
+    Char(char),
+    Digit,
+    Letter,
+    Upper,
+    Lower,
+    Symbol,
+}
+
+impl Class {
+    pub fn contains(&self, c: char) -> bool {
+        match *self {
+            Class::Char(expected) => c == expected,
+            Class::Digit => c.is_ascii_digit(),
+            Class::Letter => c.is_alphabetic(),
+            Class::Upper => c.is_uppercase(),
+            Class::Lower => c.is_lowercase(),
+            Class::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    fn count(&self, password: &str) -> usize {
+        password.chars().filter(|&c| self.contains(c)).count()
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Class::Char(c) => write!(f, "'{}'", c),
+            Class::Digit => f.write_str("digit"),
+            Class::Letter => f.write_str("letter"),
+            Class::Upper => f.write_str("upper"),
+            Class::Lower => f.write_str("lower"),
+            Class::Symbol => f.write_str("symbol"),
+        }
+    }
+}
+
+pub struct AtLeast {
+    pub count: usize,
+    pub class: Class,
+}
+
+impl Policy for AtLeast {
+    fn allows(&self, password: &str) -> bool {
+        self.class.count(password) >= self.count
+    }
+}
+
+impl fmt::Display for AtLeast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at least {} of {}", self.count, self.class)
+    }
+}
+
+pub struct AtMost {
+    pub count: usize,
+    pub class: Class,
+}
+
+impl Policy for AtMost {
+    fn allows(&self, password: &str) -> bool {
+        self.class.count(password) <= self.count
+    }
+}
+
+impl fmt::Display for AtMost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at most {} of {}", self.count, self.class)
+    }
+}
+
+/// The sled rental place's policy.
+pub struct Between {
+    pub min: usize,
+    pub max: usize,
+    pub class: Class,
+}
+
+impl Policy for Between {
+    fn allows(&self, password: &str) -> bool {
+        (self.min..=self.max).contains(&self.class.count(password))
+    }
+}
+
+impl fmt::Display for Between {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "between {} and {} of {}", self.min, self.max, self.class)
+    }
+}
+
+/// No character repeated more than `longest` times in a row.
+pub struct NoRunsLongerThan {
+    pub longest: usize,
+}
+
+impl Policy for NoRunsLongerThan {
+    fn allows(&self, password: &str) -> bool {
+        let mut run = 0;
+        let mut previous = None;
+        for c in password.chars() {
+            run = if previous == Some(c) { run + 1 } else { 1 };
+            if run > self.longest {
+                return false;
+            }
+            previous = Some(c);
+        }
+        true
+    }
+}
+
+impl fmt::Display for NoRunsLongerThan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no runs longer than {}", self.longest)
+    }
+}
+
+pub struct ContainsOneOf {
+    pub chars: String,
+}
+
+impl Policy for ContainsOneOf {
+    fn allows(&self, password: &str) -> bool {
+        password.chars().any(|c| self.chars.contains(c))
+    }
+}
+
+impl fmt::Display for ContainsOneOf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "contains one of \"{}\"", self.chars)
+    }
+}
+
+/// The toboggan rental place's policy: exactly one of two positions, counting from 1, holds
+/// the class. A position of 0 or past the end of the password breaks the rule.
+pub struct ExactlyOneAt {
+    pub positions: (usize, usize),
+    pub class: Class,
+}
+
+impl Policy for ExactlyOneAt {
+    fn allows(&self, password: &str) -> bool {
+        let at = |position: usize| {
+            password
+                .chars()
+                .nth(position.checked_sub(1)?)
+                .map(|c| self.class.contains(c))
+        };
+        match (at(self.positions.0), at(self.positions.1)) {
+            (Some(first), Some(second)) => first ^ second,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ExactlyOneAt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "exactly one of positions {} and {} is {}",
+            self.positions.0, self.positions.1, self.class
+        )
+    }
+}
+
+/// Parses one rule per line, skipping blank lines and `#` comments.
+///
+/// The rules are:
+///
+/// ```text
+/// at least <n> of <class>
+/// at most <n> of <class>
+/// between <min> and <max> of <class>
+/// no runs longer than <n>
+/// contains one of "<characters>"
+/// exactly one of positions <first> and <second> is <class>
+/// ```
+///
+/// where a class is a quoted character like `'a'`, or one of `digit`, `letter`, `upper`,
+/// `lower` or `symbol`.
+pub fn parse_policies(source: &str) -> Result<Vec<Box<dyn Policy>>, ParseError> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| parse_policy(source, line))
+        .collect()
+}
+
+/// Parses a single rule, which must be a slice of `source`.
+pub fn parse_policy(source: &str, rule: &str) -> Result<Box<dyn Policy>, ParseError> {
+    let mut cursor = Cursor { source, rest: rule };
+    let policy: Box<dyn Policy> = if cursor.keywords("at least") {
+        let count = cursor.number()?;
+        cursor.expect("of")?;
+        Box::new(AtLeast {
+            count,
+            class: cursor.class()?,
+        })
+    } else if cursor.keywords("at most") {
+        let count = cursor.number()?;
+        cursor.expect("of")?;
+        Box::new(AtMost {
+            count,
+            class: cursor.class()?,
+        })
+    } else if cursor.keywords("between") {
+        let min = cursor.number()?;
+        cursor.expect("and")?;
+        let max = cursor.number()?;
+        cursor.expect("of")?;
+        Box::new(Between {
+            min,
+            max,
+            class: cursor.class()?,
+        })
+    } else if cursor.keywords("no runs longer than") {
+        Box::new(NoRunsLongerThan {
+            longest: cursor.number()?,
+        })
+    } else if cursor.keywords("contains one of") {
+        Box::new(ContainsOneOf {
+            chars: cursor.quoted('"')?.to_string(),
+        })
+    } else if cursor.keywords("exactly one of positions") {
+        let first = cursor.position()?;
+        cursor.expect("and")?;
+        let second = cursor.position()?;
+        cursor.expect("is")?;
+        Box::new(ExactlyOneAt {
+            positions: (first, second),
+            class: cursor.class()?,
+        })
+    } else {
+        return Err(cursor.error("expected a rule like \"at least 1 of digit\""));
+    };
+    cursor.end()?;
+    Ok(policy)
+}
+
+/// The unparsed end of a rule.
+struct Cursor<'a> {
+    source: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    /// Consumes `words` if the rule continues with them, however they're spaced.
+    fn keywords(&mut self, words: &str) -> bool {
+        let mut rest = self.rest;
+        for word in words.split_whitespace() {
+            rest = rest.trim_start();
+            match rest.strip_prefix(word) {
+                Some(after) if !after.starts_with(|c: char| c.is_alphanumeric()) => rest = after,
+                _ => return false,
+            }
+        }
+        self.rest = rest;
+        true
+    }
+
+    fn expect(&mut self, word: &str) -> Result<(), ParseError> {
+        if self.keywords(word) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected \"{}\"", word)))
+        }
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.rest = self.rest.trim_start();
+        let end = self
+            .rest
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        word
+    }
+
+    fn number(&mut self) -> Result<usize, ParseError> {
+        let word = self.word();
+        parse_at(self.source, word)
+    }
+
+    fn position(&mut self) -> Result<usize, ParseError> {
+        let word = self.word();
+        match parse_at(self.source, word)? {
+            0 => Err(ParseError::at(self.source, word, "positions count from 1")),
+            position => Ok(position),
+        }
+    }
+
+    fn class(&mut self) -> Result<Class, ParseError> {
+        self.rest = self.rest.trim_start();
+        if self.rest.starts_with('\'') {
+            let start = self.rest;
+            let quoted = self.quoted('\'')?;
+            let mut chars = quoted.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Class::Char(c)),
+                _ => Err(ParseError::at(
+                    self.source,
+                    &start[..quoted.len() + 2],
+                    "expected a single character",
+                )),
+            };
+        }
+        let word = self.word();
+        match word {
+            "digit" => Ok(Class::Digit),
+            "letter" => Ok(Class::Letter),
+            "upper" => Ok(Class::Upper),
+            "lower" => Ok(Class::Lower),
+            "symbol" => Ok(Class::Symbol),
+            _ => Err(ParseError::at(
+                self.source,
+                word,
+                "expected a quoted character, digit, letter, upper, lower or symbol",
+            )),
+        }
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<&'a str, ParseError> {
+        self.rest = self.rest.trim_start();
+        let expected = format!("expected text in {}quotes{}", quote, quote);
+        let inside = self
+            .rest
+            .strip_prefix(quote)
+            .ok_or_else(|| self.error(expected.clone()))?;
+        let end = inside.find(quote).ok_or_else(|| {
+            ParseError::at(self.source, &inside[inside.len()..], expected.clone())
+        })?;
+        self.rest = &inside[end + 1..];
+        Ok(&inside[..end])
+    }
+
+    fn end(&mut self) -> Result<(), ParseError> {
+        self.rest = self.rest.trim_start();
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("expected the end of the rule"))
+        }
+    }
+
+    /// An error about the next word, or the end of the rule if there isn't one.
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        let rest = self.rest.trim_start();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        ParseError::at(self.source, &rest[..end], message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_policies, parse_policy};
+
+    #[test]
+    fn rules_round_trip() {
+        let source = "\
+            at least 2 of digit\n\
+            # comment\n\
+            at most 1 of 'x'\n\
+            between 1 and 3 of upper\n\
+            no runs longer than 2\n\
+            contains one of \"!@#\"\n\
+            exactly one of positions 1 and 3 is 'a'";
+        let policies = parse_policies(source).unwrap();
+        let rules: Vec<_> = policies.iter().map(|policy| policy.to_string()).collect();
+        let expected: Vec<_> = source
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect();
+        assert_eq!(rules, expected);
+    }
+
+    #[test]
+    fn reports_violations() {
+        let source = "at least 1 of digit\nno runs longer than 2\ncontains one of \"!?\"";
+        let policies = parse_policies(source).unwrap();
+        let violated = |password| {
+            policies
+                .iter()
+                .filter(|policy| !policy.allows(password))
+                .map(|policy| policy.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert!(violated("ab1!").is_empty());
+        assert_eq!(
+            violated("aaab!"),
+            ["at least 1 of digit", "no runs longer than 2"]
+        );
+        assert_eq!(violated("a1"), ["contains one of \"!?\""]);
+    }
+
+    #[test]
+    fn positioned_errors() {
+        let source = "at least two of digit";
+        let error = parse_policy(source, source).err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (10, "two"));
+        let source = "exactly one of positions 1 and 0 is 'a'";
+        let error = parse_policy(source, source).err().unwrap();
+        assert_eq!(error.column, 32);
+        let source = "at most 1 of 'ab'";
+        let error = parse_policy(source, source).err().unwrap();
+        assert_eq!(error.column, 14);
+        let source = "between 1 and 2 of digit please";
+        let error = parse_policy(source, source).err().unwrap();
+        assert_eq!(error.text, "please");
+    }
+}
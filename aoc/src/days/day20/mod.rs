@@ -0,0 +1,336 @@
+use crate::records::{parse_records, Record};
+use crate::{parse_at, Grid, ParseError, Solution};
+use lazy_static::lazy_static;
+use multimap::MultiMap;
+use std::collections::HashMap;
+use std::fmt;
+
+const HASHES_PER_MONSTER: usize = 15;
+const PIECE_SIZE_WITH_EDGES: usize = 10;
+const PIECE_SIZE: usize = 8;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Piece>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Piece>, ParseError> {
+        parse_records(input, |record| Piece::from_record(input, record))
+    }
+
+    fn part1(pieces: &Vec<Piece>) -> usize {
+        let mapping = build_mapping(pieces);
+        let corners = find_corners(&mapping);
+        corners.iter().fold(1, |acc, piece| acc * piece.id)
+    }
+
+    fn part2(pieces: &Vec<Piece>) -> usize {
+        let mapping = build_mapping(pieces);
+        let corners = find_corners(&mapping);
+        water_roughness(pieces, &mapping, &corners)
+    }
+}
+
+fn water_roughness<'a>(
+    pieces: &[Piece],
+    mapping: &'a MultiMap<&'a [Pixel], &Piece>,
+    corners: &[&'a Piece],
+) -> usize {
+    let completed_puzzle = build_puzzle(pieces, mapping, corners);
+    let monsters = find_monsters(&completed_puzzle);
+
+    completed_puzzle
+        .iter()
+        .filter(|&&pixel| pixel == Pixel::HASH)
+        .count()
+        - (monsters * HASHES_PER_MONSTER)
+}
+
+fn build_mapping(pieces: &[Piece]) -> MultiMap<&[Pixel], &Piece> {
+    pieces
+        .iter()
+        .flat_map(|piece| {
+            piece
+                .unflipped_edges
+                .iter()
+                .chain(piece.flipped_edges.iter())
+                .map(move |edge| (edge.as_slice(), piece))
+        })
+        .collect()
+}
+
+fn find_corners<'a>(mapping: &MultiMap<&[Pixel], &'a Piece>) -> Vec<&'a Piece> {
+    let mut corners: Vec<&Piece> = Vec::new();
+    let mut seen_pieces: HashMap<&Piece, usize> = HashMap::new();
+    mapping.iter_all().for_each(|(_edge, pieces)| {
+        match (pieces.len(), seen_pieces.get_mut(pieces[0])) {
+            (1, Some(3)) => {
+                corners.push(pieces[0]);
+            }
+            (1, Some(x)) => *x += 1,
+            (1, None) => {
+                seen_pieces.insert(pieces[0], 1);
+            }
+            _ => {}
+        };
+    });
+    assert_eq!(4, corners.len());
+    corners
+}
+
+fn build_puzzle<'a>(
+    pieces: &[Piece],
+    mapping: &'a MultiMap<&'a [Pixel], &Piece>,
+    corners: &[&'a Piece],
+) -> Grid<Pixel> {
+    let piece_width = (pieces.len() as f64).sqrt() as usize;
+    let pixel_width = piece_width * PIECE_SIZE;
+    let mut puzzle = Grid::from_fn(pixel_width, pixel_width, |_, _| Pixel::DOT);
+
+    let first_piece = corners[0];
+    let left_and_top: Vec<_> = first_piece
+        .unflipped_edges
+        .iter()
+        .enumerate()
+        .filter(|(_, edge)| mapping.get_vec(edge.as_slice()).unwrap().len() == 1)
+        .collect();
+
+    let mut previous_right;
+    let mut previous_bottom = if left_and_top[0].0 == 0 && left_and_top[1].0 == 3 {
+        reversed(left_and_top[0].1)
+    } else {
+        reversed(left_and_top[1].1)
+    };
+
+    let mut current_left_piece = None;
+    let mut current_piece;
+
+    for row in 0..piece_width {
+        let top = reversed(&previous_bottom);
+        current_left_piece = get_next_piece(mapping, &top, current_left_piece);
+        current_piece = current_left_piece;
+
+        let oriented = current_piece.unwrap().oriented_top(&top);
+        copy_into(&mut puzzle, &oriented, (row, 0));
+
+        previous_right = current_piece.unwrap().right_side(&top).to_vec();
+        previous_bottom = current_piece.unwrap().opposite_side(&top).to_vec();
+
+        for column in 1..piece_width {
+            let left = reversed(&previous_right);
+            current_piece = get_next_piece(mapping, &left, current_piece);
+
+            let oriented = current_piece.unwrap().oriented_left(&left);
+            copy_into(&mut puzzle, &oriented, (row, column));
+
+            previous_right = current_piece.unwrap().opposite_side(&left).to_vec();
+        }
+    }
+
+    puzzle
+}
+
+fn reversed(side: &[Pixel]) -> Vec<Pixel> {
+    side.iter().rev().copied().collect()
+}
+
+fn get_next_piece<'a>(
+    mapping: &MultiMap<&[Pixel], &'a Piece>,
+    side: &[Pixel],
+    current_piece: Option<&Piece>,
+) -> Option<&'a Piece> {
+    mapping
+        .get_vec(side)
+        .unwrap()
+        .iter()
+        .copied()
+        .find(|&piece| current_piece != Some(piece))
+}
+
+/// Copies a piece's inside, without its border, to its `(row, column)` place in the puzzle.
+fn copy_into(target: &mut Grid<Pixel>, source_piece: &Grid<Pixel>, position: (usize, usize)) {
+    target.paste(
+        position.1 * PIECE_SIZE,
+        position.0 * PIECE_SIZE,
+        &source_piece.subgrid(1, 1, PIECE_SIZE, PIECE_SIZE),
+    );
+}
+
+const MONSTER_WIDTH: usize = 20;
+const MONSTER_HEIGHT: usize = 3;
+
+fn find_monsters(puzzle: &Grid<Pixel>) -> usize {
+    let mut found = 0;
+    'outer: for mirror in [true, false] {
+        for rotation_order in 0..4 {
+            let transformed_puzzle = if mirror {
+                puzzle.mirrored().rotated(rotation_order)
+            } else {
+                puzzle.rotated(rotation_order)
+            };
+            found = find_monsters_single_orientation(&transformed_puzzle);
+            if found > 0 {
+                break 'outer;
+            }
+        }
+    }
+
+    found
+}
+
+fn find_monsters_single_orientation(puzzle: &Grid<Pixel>) -> usize {
+    let mut found = 0;
+    for row in 0..(puzzle.height() - MONSTER_HEIGHT) {
+        for column in 0..(puzzle.width() - MONSTER_WIDTH) {
+            found += match_monster(puzzle, (column, row)) as usize;
+        }
+    }
+    found
+}
+
+fn match_monster(puzzle: &Grid<Pixel>, (x, y): (usize, usize)) -> bool {
+    lazy_static! {
+        static ref MONSTER: Grid<Pixel> = Grid::parse(
+            "..................#.\n\
+             #....##....##....###\n\
+             .#..#..#..#..#..#...",
+            Pixel::from_char,
+            "expected '#' or '.'",
+        )
+        .unwrap();
+    }
+    MONSTER
+        .positions()
+        .filter(|(_, &pixel)| pixel == Pixel::HASH)
+        .all(|((i, j), _)| puzzle[(x + i, y + j)] == Pixel::HASH)
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Piece {
+    pub id: usize,
+    data: Grid<Pixel>,
+    pub unflipped_edges: Vec<Vec<Pixel>>,
+    pub flipped_edges: Vec<Vec<Pixel>>,
+}
+
+impl Piece {
+    pub fn from_record(input: &str, record: &Record) -> Result<Piece, ParseError> {
+        let header = record.lines[0];
+        let id = match header.trim().strip_prefix("Tile ") {
+            Some(rest) => parse_at(input, rest.strip_suffix(":").unwrap_or(rest))?,
+            None => return Err(ParseError::at(input, header, "expected \"Tile <id>:\"")),
+        };
+
+        let rows = &record.lines[1..];
+        if rows.len() != PIECE_SIZE_WITH_EDGES {
+            return Err(ParseError::at(
+                input,
+                header,
+                format!(
+                    "expected {} rows of pixels, found {}",
+                    PIECE_SIZE_WITH_EDGES,
+                    rows.len()
+                ),
+            ));
+        }
+        let data = Grid::parse_lines(
+            input,
+            rows.iter().copied(),
+            Pixel::from_char,
+            "expected '#' or '.'",
+        )?;
+        if data.width() != PIECE_SIZE_WITH_EDGES {
+            return Err(ParseError::at(
+                input,
+                rows[0],
+                format!("expected a row of {} pixels", PIECE_SIZE_WITH_EDGES),
+            ));
+        }
+
+        let last = PIECE_SIZE_WITH_EDGES - 1;
+        let unflipped_edges = vec![
+            data.row(0).to_vec(),
+            data.column(last).copied().collect(),
+            reversed(data.row(last)),
+            data.column(0).rev().copied().collect(),
+        ];
+        let flipped_edges = vec![
+            reversed(data.row(0)),
+            data.column(0).copied().collect(),
+            data.row(last).to_vec(),
+            data.column(last).rev().copied().collect(),
+        ];
+
+        Ok(Piece {
+            id,
+            data,
+            unflipped_edges,
+            flipped_edges,
+        })
+    }
+
+    fn oriented_top(&self, side: &[Pixel]) -> Grid<Pixel> {
+        self.oriented(side, 0)
+    }
+
+    fn oriented_left(&self, side: &[Pixel]) -> Grid<Pixel> {
+        self.oriented(side, 3)
+    }
+
+    fn oriented(&self, side: &[Pixel], order: usize) -> Grid<Pixel> {
+        match (
+            self.unflipped_edges.iter().position(|edge| edge == side),
+            self.flipped_edges.iter().position(|edge| edge == side),
+        ) {
+            (Some(x), None) => self.data.rotated((4 + order - x) % 4),
+            (None, Some(x)) => self.data.mirrored().rotated((4 + order - x) % 4),
+            _ => panic!("Unexpected side result"),
+        }
+    }
+
+    pub fn right_side(&self, side: &[Pixel]) -> &[Pixel] {
+        self.relative_side(side, 1)
+    }
+
+    pub fn opposite_side(&self, side: &[Pixel]) -> &[Pixel] {
+        self.relative_side(side, 2)
+    }
+
+    fn relative_side(&self, side: &[Pixel], relative_order: usize) -> &[Pixel] {
+        match (
+            self.unflipped_edges.iter().position(|edge| edge == side),
+            self.flipped_edges.iter().position(|edge| edge == side),
+        ) {
+            (Some(x), None) => &self.unflipped_edges[(x + relative_order) % 4],
+            (None, Some(x)) => &self.flipped_edges[(x + relative_order) % 4],
+            _ => panic!("Unexpected side result"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd)]
+pub enum Pixel {
+    HASH,
+    DOT,
+}
+
+impl Pixel {
+    pub fn from_char(c: char) -> Option<Pixel> {
+        match c {
+            '#' => Some(Pixel::HASH),
+            '.' => Some(Pixel::DOT),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Pixel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Pixel::HASH => "#",
+            Pixel::DOT => ".",
+        })
+    }
+}
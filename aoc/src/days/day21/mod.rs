@@ -0,0 +1,132 @@
+use crate::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub type Recipe = (HashSet<String>, HashSet<String>);
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Recipe>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Recipe>, ParseError> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn part1(recipes: &Vec<Recipe>) -> usize {
+        let recipes = borrow_recipes(recipes);
+        let (all_ingredients, all_allergens) = narrow_allergens(&recipes);
+        count_safe_ingredients(&all_ingredients, &all_allergens, &recipes)
+    }
+
+    fn part2(recipes: &Vec<Recipe>) -> String {
+        let recipes = borrow_recipes(recipes);
+        let (_, all_allergens) = narrow_allergens(&recipes);
+        dangerous_ingredients(&all_allergens)
+    }
+}
+
+fn borrow_recipes(recipes: &[Recipe]) -> Vec<(HashSet<&str>, HashSet<&str>)> {
+    recipes
+        .iter()
+        .map(|(ingredients, allergens)| {
+            (
+                ingredients.iter().map(|s| s.as_str()).collect(),
+                allergens.iter().map(|s| s.as_str()).collect(),
+            )
+        })
+        .collect()
+}
+
+fn narrow_allergens<'a>(
+    recipes: &Vec<(HashSet<&'a str>, HashSet<&'a str>)>,
+) -> (HashSet<&'a str>, HashMap<&'a str, HashSet<&'a str>>) {
+    let all_ingredients: HashSet<_> = recipes
+        .iter()
+        .flat_map(|(ingredients, _allergens)| ingredients.clone())
+        .collect();
+    let mut all_allergens: HashMap<_, _> = recipes
+        .iter()
+        .flat_map(|(_ingredients, allergens)| {
+            allergens
+                .iter()
+                .map(|allergen| (*allergen, all_ingredients.clone()))
+        })
+        .collect();
+
+    recipes.iter().for_each(|(ingredients, allergens)| {
+        allergens.iter().for_each(|allergen| {
+            all_allergens.insert(allergen, &all_allergens[allergen] & ingredients);
+        });
+    });
+
+    (all_ingredients, all_allergens)
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Recipe, ParseError> {
+    let mut ingr_allerg = line
+        .trim_end_matches(")")
+        .splitn(2, "(contains")
+        .map(|s| s.trim());
+    let ingredients = ingr_allerg
+        .next()
+        .unwrap()
+        .split_whitespace()
+        .map(|s| s.to_string())
+        .collect();
+    let allergens = ingr_allerg
+        .next()
+        .ok_or_else(|| {
+            ParseError::at(
+                input,
+                line,
+                "expected \"<ingredients> (contains <allergens>)\"",
+            )
+        })?
+        .split(", ")
+        .map(|s| s.to_string())
+        .collect();
+    Ok((ingredients, allergens))
+}
+
+fn count_safe_ingredients(
+    all_ingredients: &HashSet<&str>,
+    all_allergens: &HashMap<&str, HashSet<&str>>,
+    recipes: &Vec<(HashSet<&str>, HashSet<&str>)>,
+) -> usize {
+    let possible_allergen_ingredients: HashSet<_> = all_allergens
+        .values()
+        .flat_map(|ingredients| ingredients.clone())
+        .collect();
+    let non_allergens = all_ingredients - &possible_allergen_ingredients;
+    recipes
+        .iter()
+        .map(|(ingredients, _allergens)| (ingredients & &non_allergens).len())
+        .sum()
+}
+
+fn dangerous_ingredients(all_allergens: &HashMap<&str, HashSet<&str>>) -> String {
+    let mut iter_allergens = all_allergens.clone();
+    let mut matched_ingredients: HashSet<&str> = HashSet::with_capacity(all_allergens.len());
+    let mut final_allergens: Vec<(&str, &str)> = vec![];
+    while matched_ingredients.len() < all_allergens.len() {
+        iter_allergens.retain(|allergen, ingredients| {
+            let possible_ingredients = &*ingredients - &matched_ingredients;
+            if possible_ingredients.len() == 1 {
+                let ingredient = possible_ingredients.iter().next().unwrap();
+                matched_ingredients.insert(ingredient);
+                final_allergens.push((allergen, ingredient));
+                false
+            } else {
+                true
+            }
+        });
+    }
+    final_allergens.sort_unstable();
+    final_allergens
+        .iter()
+        .map(|(_allergen, ingredient)| *ingredient)
+        .collect::<Vec<&str>>()
+        .join(",")
+}
@@ -0,0 +1,151 @@
+use crate::records::parse_records;
+use crate::{parse_at, ParseError, Solution};
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<VecDeque<usize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<VecDeque<usize>>, ParseError> {
+        // A tie between two equal cards has no winner, so each card may only be dealt once.
+        let mut seen = HashSet::new();
+        let decks = parse_records(input, |record| {
+            let header = record.lines[0];
+            if header != format!("Player {}:", record.number) {
+                return Err(ParseError::at(
+                    input,
+                    header,
+                    format!("expected \"Player {}:\"", record.number),
+                ));
+            }
+            record.lines[1..]
+                .iter()
+                .map(|line| {
+                    let card = parse_at(input, line)?;
+                    if !seen.insert(card) {
+                        return Err(ParseError::at(input, line, "cards must not repeat"));
+                    }
+                    Ok(card)
+                })
+                .collect()
+        })?;
+        if decks.len() != 2 {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                format!("expected two decks, found {}", decks.len()),
+            ));
+        }
+        Ok(decks)
+    }
+
+    fn part1(decks: &Vec<VecDeque<usize>>) -> usize {
+        play_combat(&mut decks.clone())
+    }
+
+    fn part2(decks: &Vec<VecDeque<usize>>) -> usize {
+        play_recursive_combat(&mut decks.clone())
+    }
+}
+
+fn play_combat(decks: &mut Vec<VecDeque<usize>>) -> usize {
+    while !decks[0].is_empty() && !decks[1].is_empty() {
+        perform_turn(decks);
+    }
+    calculate_score(decks)
+}
+
+fn play_recursive_combat(decks: &mut Vec<VecDeque<usize>>) -> usize {
+    play_recursive_game(decks);
+    calculate_score(decks)
+}
+
+fn play_recursive_game(decks: &mut Vec<VecDeque<usize>>) -> usize {
+    let mut visited_states: HashSet<Vec<VecDeque<usize>>> = HashSet::new();
+    while !decks[0].is_empty() && !decks[1].is_empty() {
+        if visited_states.contains(decks) {
+            return 0;
+        }
+        visited_states.insert(decks.clone());
+        perform_recursive_turn(decks);
+    }
+    match (decks[0].is_empty(), decks[1].is_empty()) {
+        (true, false) => 1,
+        (false, true) => 0,
+        _ => panic!("Unexpected game end: {:#?}", decks),
+    }
+}
+
+fn perform_recursive_turn(decks: &mut Vec<VecDeque<usize>>) {
+    let cards: Vec<usize> = decks
+        .iter_mut()
+        .map(|deck| deck.pop_front().unwrap())
+        .collect();
+    if decks[0].len() >= cards[0] && decks[1].len() >= cards[1] {
+        let mut mini_decks: Vec<VecDeque<usize>> = decks
+            .iter()
+            .zip(cards.iter())
+            .map(|(deck, card)| deck.range(..card).copied().collect())
+            .collect();
+        match play_recursive_game(&mut mini_decks) {
+            0 => decks[0].extend(cards.iter()),
+            1 => decks[1].extend(cards.iter().rev()),
+            x => panic!("Unexpected winner: {}", x),
+        }
+    } else {
+        decks
+            .iter_mut()
+            .zip(cards)
+            .for_each(|(deck, card)| deck.push_front(card));
+        perform_turn(decks)
+    }
+}
+
+fn perform_turn(decks: &mut Vec<VecDeque<usize>>) {
+    let card0 = decks[0].pop_front().unwrap();
+    let card1 = decks[1].pop_front().unwrap();
+    match card0.cmp(&card1) {
+        Ordering::Greater => decks[0].extend([card0, card1]),
+        Ordering::Less => decks[1].extend([card1, card0]),
+        Ordering::Equal => panic!("Unexpected tie: {}, {}\n{:?}", card0, card1, decks),
+    }
+}
+
+fn calculate_score(decks: &Vec<VecDeque<usize>>) -> usize {
+    let deck = match (decks[0].is_empty(), decks[1].is_empty()) {
+        (true, false) => &decks[1],
+        (false, true) => &decks[0],
+        _ => panic!("Unexpected game end: {:#?}", decks),
+    };
+    let number_of_cards = deck.len();
+    deck.iter()
+        .enumerate()
+        .map(|(i, card_value)| card_value * (number_of_cards - i))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day22;
+    use crate::Solution;
+
+    #[test]
+    fn example() {
+        let decks =
+            Day22::parse("Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10\n").unwrap();
+        assert_eq!(Day22::part1(&decks), 306);
+        assert_eq!(Day22::part2(&decks), 291);
+    }
+
+    #[test]
+    fn cards_must_not_repeat() {
+        let err = Day22::parse("Player 1:\n1\n\nPlayer 2:\n1").unwrap_err();
+        assert_eq!((err.record, err.line, err.column), (Some(2), 5, 1));
+        let err = Day22::parse("Player 1:\n2\n3\n2\n\nPlayer 2:\n1").unwrap_err();
+        assert_eq!((err.record, err.line), (Some(1), 4));
+    }
+}
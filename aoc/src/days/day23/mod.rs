@@ -1,43 +1,77 @@
-#![feature(linked_list_cursors)]
-
+use crate::{ParseError, Solution};
 use std::collections::HashMap;
 
 const CUPS_TO_MOVE: usize = 3;
+/// The current cup, the ones picked up, and somewhere else to put them down.
+const MIN_CUPS: usize = CUPS_TO_MOVE + 2;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = String;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let cups = input.trim();
+        let count = cups.chars().count();
+        if count < MIN_CUPS {
+            return Err(ParseError::at(
+                input,
+                cups,
+                format!("expected at least {} cups", MIN_CUPS),
+            ));
+        }
+        // The labels must be 1 to however many cups there are, each used once.
+        let mut seen = [false; 10];
+        for (i, c) in cups.char_indices() {
+            let at = &cups[i..i + c.len_utf8()];
+            let label = match c.to_digit(10) {
+                Some(label) if label >= 1 && label as usize <= count => label as usize,
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        at,
+                        format!("expected a cup label from 1 to {}", count),
+                    ))
+                }
+            };
+            if seen[label] {
+                return Err(ParseError::at(input, at, "cup labels must not repeat"));
+            }
+            seen[label] = true;
+        }
+        Ok(cups.to_string())
+    }
 
-fn main() {
-    let input_string = include_str!("input.txt").trim();
-    println!(
-        "part1: {}",
+    fn part1(input_string: &String) -> String {
         solve(
             &mut Circle::from_str(input_string),
             100,
-            input_string.len() - 1
+            input_string.len() - 1,
         )
         .iter()
         .map(|i| i.to_string())
         .collect::<Vec<_>>()
-        .join(""),
-    );
-    println!(
-        "part2: {}",
+        .join("")
+    }
+
+    fn part2(input_string: &String) -> usize {
         solve(
-            &mut Circle::from_str_extended(input_string, 1000_000),
+            &mut Circle::from_str_extended(input_string, 1_000_000),
             10_000_000,
             2,
         )
         .iter()
-        .fold(1, |acc, i| acc * i),
-    )
+        .product()
+    }
 }
 
 fn solve(circle: &mut Circle, move_count: usize, result_count: usize) -> Vec<usize> {
     let mut current_cup = circle.first;
-    for i in 0..move_count {
+    for _ in 0..move_count {
         circle.move_cups(current_cup);
         current_cup = circle.following(current_cup);
-        if i % 100_000 == 0 {
-            println!("{}", i);
-        }
     }
 
     circle.order_from(1, result_count)
@@ -71,8 +105,7 @@ impl Circle {
             .unwrap();
         let last = starting_position
             .chars()
-            .rev()
-            .next()
+            .next_back()
             .unwrap()
             .to_string()
             .parse()
@@ -97,8 +130,7 @@ impl Circle {
             .unwrap();
         let last = starting_position
             .chars()
-            .rev()
-            .next()
+            .next_back()
             .unwrap()
             .to_string()
             .parse()
@@ -168,3 +200,29 @@ impl Circle {
             .insert(*to_splice.last().unwrap(), Some(following));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day23;
+    use crate::Solution;
+
+    #[test]
+    fn example() {
+        let cups = Day23::parse("389125467\n").unwrap();
+        assert_eq!(Day23::part1(&cups), "67384529");
+    }
+
+    #[test]
+    fn labels_must_be_a_permutation() {
+        let column = |input| Day23::parse(input).unwrap_err().column;
+        assert_eq!(column("1"), 1);
+        assert_eq!(column("11"), 1);
+        assert_eq!(column("9"), 1);
+        assert_eq!(column("102"), 1);
+        assert_eq!(column("1234"), 1);
+        assert_eq!(column("12344"), 5);
+        assert_eq!(column("10234"), 2);
+        assert_eq!(column("123457"), 6);
+        assert!(Day23::parse("25143").is_ok());
+    }
+}
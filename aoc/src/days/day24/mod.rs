@@ -0,0 +1,101 @@
+use crate::life::{Life, Neighborhood, Rule};
+use crate::{ParseError, Solution};
+
+const ITERATIONS: usize = 100;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_instruction(input, line))
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<Vec<i32>>) -> usize {
+        lobby(instructions).population()
+    }
+
+    fn part2(instructions: &Vec<Vec<i32>>) -> usize {
+        let mut lobby = lobby(instructions);
+        lobby.run(ITERATIONS);
+        lobby.population()
+    }
+}
+
+/// Black tiles with one or two black neighbours stay black, and white tiles with exactly two
+/// become black.
+fn lobby(instructions: &[Vec<i32>]) -> Life {
+    let mut lobby = Life::new(Neighborhood::Hex, Rule::new(&[2], &[1, 2]));
+    for tile in instructions {
+        lobby.toggle(tile.clone());
+    }
+    lobby
+}
+
+/// Follows the directions from the reference tile to the `(q, r)` axial coordinates of a tile.
+fn parse_instruction(input: &str, instruction: &str) -> Result<Vec<i32>, ParseError> {
+    let mut vertical_is_north: Option<bool> = None;
+    let mut position = vec![0, 0];
+    for (i, letter) in instruction.char_indices() {
+        let at = &instruction[i..i + letter.len_utf8()];
+        match letter {
+            'n' | 's' if vertical_is_north.is_some() => {
+                return Err(ParseError::at(input, at, "expected 'e' or 'w'"))
+            }
+            'n' => vertical_is_north = Some(true),
+            'e' => match vertical_is_north.take() {
+                Some(true) => {
+                    position[0] += 1;
+                    position[1] -= 1;
+                }
+                Some(false) => position[1] += 1,
+                None => position[0] += 1,
+            },
+            's' => vertical_is_north = Some(false),
+            'w' => match vertical_is_north.take() {
+                Some(true) => position[1] -= 1,
+                Some(false) => {
+                    position[0] -= 1;
+                    position[1] += 1;
+                }
+                None => position[0] -= 1,
+            },
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    at,
+                    "expected one of 'e', 'se', 'sw', 'w', 'nw' or 'ne'",
+                ))
+            }
+        }
+    }
+    if vertical_is_north.is_some() {
+        let end = &instruction[instruction.len()..];
+        return Err(ParseError::at(input, end, "expected 'e' or 'w'"));
+    }
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lobby, parse_instruction};
+
+    #[test]
+    fn correct_neighbors() {
+        let input = include_str!("test_input_2.txt");
+        let instructions: Vec<_> = input
+            .lines()
+            .map(|line| parse_instruction(input, line).unwrap())
+            .collect();
+        assert_eq!(instructions, [vec![1, 0], vec![0, 0]]);
+        let lobby = lobby(&instructions);
+        assert_eq!(lobby.population(), 2);
+        assert!(lobby.is_alive(&[0, 0]) && lobby.is_alive(&[1, 0]));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{parse_at, ParseError, Solution};
+use num::traits::{One, Zero};
+use num::{Bounded, Num};
+use std::collections::HashSet;
+use std::ops::ShrAssign;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const PARTS: usize = 1;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        let mut keys = Vec::with_capacity(2);
+        for line in input.lines() {
+            if keys.len() == 2 {
+                return Err(ParseError::at(input, line, "expected only two public keys"));
+            }
+            let key = parse_at(input, line)?;
+            // Every other number is some power of the subject, so the loop sizes exist.
+            if key == 0 || key >= MODULUS {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected a public key from 1 to {}", MODULUS - 1),
+                ));
+            }
+            keys.push(key);
+        }
+        if keys.len() < 2 {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                format!("expected two public keys, found {}", keys.len()),
+            ));
+        }
+        Ok(keys)
+    }
+
+    fn part1(keys: &Vec<u64>) -> u64 {
+        get_final_key(keys)
+    }
+
+    fn part2(_keys: &Vec<u64>) -> u64 {
+        unreachable!("day 25 only has one part")
+    }
+}
+
+fn get_final_key(keys: &[u64]) -> u64 {
+    let mut to_find: HashSet<u64> = keys.iter().cloned().collect();
+    let mut key_to_be = 1u64;
+    let mut loop_sizes = HashSet::with_capacity(to_find.len());
+    let mut loops = 0u64;
+
+    while !to_find.is_empty() {
+        if to_find.contains(&key_to_be) {
+            loop_sizes.insert(loops);
+            to_find.remove(&key_to_be);
+        }
+        loops += 1;
+        key_to_be = (key_to_be * SUBJECT) % MODULUS;
+    }
+    let total_power: u64 = loop_sizes.iter().product();
+    SUBJECT.mod_power(total_power, MODULUS)
+}
+
+trait ModPower: Sized + Num + Copy + Bounded + ShrAssign + PartialOrd {
+    fn mod_power(&self, exponent: Self, modulus: Self) -> Self {
+        let zero = Zero::zero();
+        let one = One::one();
+        let two = one + one;
+        let mut base = *self % modulus;
+        let mut result = one;
+        let mut exp = exponent;
+        while exp > zero {
+            if exp % two == one {
+                result = (result * base) % modulus;
+            }
+
+            exp >>= one;
+            base = (base * base) % modulus;
+        }
+        result
+    }
+}
+
+impl ModPower for u64 {}
+
+#[cfg(test)]
+mod tests {
+    use super::Day25;
+    use crate::Solution;
+
+    #[test]
+    fn example() {
+        let keys = Day25::parse("5764801\n17807724\n").unwrap();
+        assert_eq!(Day25::part1(&keys), 14897079);
+    }
+
+    #[test]
+    fn expects_two_keys_below_the_modulus() {
+        let position = |input| {
+            let err = Day25::parse(input).unwrap_err();
+            (err.line, err.column)
+        };
+        assert_eq!(position("0\n5764801\n"), (1, 1));
+        assert_eq!(position("5764801\n20201227\n"), (2, 1));
+        assert_eq!(position("5764801\n"), (2, 1));
+        assert_eq!(position("5764801\n17807724\n1\n"), (3, 1));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{Grid, ParseError, Solution};
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tile {
+    TREE,
+    SPACE,
+}
+
+/// The map repeats to the right as far as the slope needs, so it's read with `Grid::wrapping`.
+pub type Forest = Grid<Tile>;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Forest;
+    type Part1 = i32;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Forest, ParseError> {
+        Grid::parse(
+            input,
+            |c| match c {
+                '#' => Some(Tile::TREE),
+                '.' => Some(Tile::SPACE),
+                _ => None,
+            },
+            "expected '#' or '.'",
+        )
+    }
+
+    fn part1(forest: &Forest) -> i32 {
+        count_trees(forest, Slope { right: 3, down: 1 }) as i32
+    }
+
+    fn part2(forest: &Forest) -> i64 {
+        let mut total: i64 = 1;
+        for (right, down) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)] {
+            total *= count_trees(forest, Slope { right, down }) as i64;
+        }
+        total
+    }
+}
+
+/// How far the toboggan moves each step; negative `right` heads left.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+/// Counts the trees hit going from the top left to the bottom of the forest.
+pub fn count_trees(forest: &Forest, slope: Slope) -> usize {
+    assert!(slope.down > 0, "the toboggan only goes downhill");
+    (0..forest.height())
+        .step_by(slope.down)
+        .enumerate()
+        .filter(|&(step, y)| {
+            let x = step as isize * slope.right;
+            *forest.wrapping(x, y as isize) == Tile::TREE
+        })
+        .count()
+}
+
+/// Every distinct slope whose steps go at most `bound` across and down, fewest trees first.
+///
+/// Slopes are kept in lowest terms, so right 2, down 2 is only tried as right 1, down 1.
+pub fn rank_slopes(forest: &Forest, bound: usize) -> Vec<(Slope, usize)> {
+    let bound = bound as isize;
+    let mut ranked: Vec<_> = (1..=bound)
+        .flat_map(|down| (-bound..=bound).map(move |right| (right, down)))
+        .filter(|&(right, down)| gcd(right.unsigned_abs(), down as usize) == 1)
+        .map(|(right, down)| {
+            let slope = Slope {
+                right,
+                down: down as usize,
+            };
+            (slope, count_trees(forest, slope))
+        })
+        .collect();
+    ranked.sort_by_key(|&(slope, trees)| (trees, slope.down, slope.right));
+    ranked
+}
+
+/// The slope hitting fewest trees among those `rank_slopes` tries.
+pub fn best_slope(forest: &Forest, bound: usize) -> Option<(Slope, usize)> {
+    rank_slopes(forest, bound).into_iter().next()
+}
+
+/// A square of the forest in a drawing of the toboggan's path.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mark {
+    Tree,
+    Open,
+    /// A tree on the path.
+    Hit,
+    /// Open ground on the path.
+    Miss,
+}
+
+impl Mark {
+    /// The colour a square is drawn in by `Grid::to_ppm`.
+    pub fn color(&self) -> [u8; 3] {
+        match self {
+            Mark::Tree => [34, 110, 50],
+            Mark::Open => [236, 240, 241],
+            Mark::Hit => [200, 30, 40],
+            Mark::Miss => [40, 90, 200],
+        }
+    }
+}
+
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Mark::Tree => "#",
+            Mark::Open => ".",
+            Mark::Hit => "X",
+            Mark::Miss => "O",
+        })
+    }
+}
+
+/// The forest with the path for `slope` marked on it, as in the puzzle text.
+///
+/// The forest is repeated sideways as many times as the path needs, to the left as well for
+/// slopes heading that way, so the top left square of the map the toboggan starts on may not
+/// be the top left of the drawing.
+pub fn draw_path(forest: &Forest, slope: Slope) -> Grid<Mark> {
+    let width = forest.width() as isize;
+    let steps = (forest.height() - 1) / slope.down;
+    let end = steps as isize * slope.right;
+    let first_copy = end.min(0).div_euclid(width);
+    let copies = end.max(0).div_euclid(width) - first_copy + 1;
+    let left = first_copy * width;
+
+    let mut drawing = Grid::from_fn(
+        (copies * width) as usize,
+        forest.height(),
+        |x, y| match forest.wrapping(left + x as isize, y as isize) {
+            Tile::TREE => Mark::Tree,
+            Tile::SPACE => Mark::Open,
+        },
+    );
+    for step in 0..=steps {
+        let x = (step as isize * slope.right - left) as usize;
+        let square = &mut drawing[(x, step * slope.down)];
+        *square = match square {
+            Mark::Tree => Mark::Hit,
+            _ => Mark::Miss,
+        };
+    }
+    drawing
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{best_slope, count_trees, draw_path, rank_slopes, Day3, Mark, Slope};
+    use crate::Solution;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn example() {
+        let forest = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&forest), 7);
+        assert_eq!(Day3::part2(&forest), 336);
+    }
+
+    #[test]
+    fn slopes_to_the_left_wrap() {
+        let forest = Day3::parse("...\n..#\n.#.").unwrap();
+        assert_eq!(count_trees(&forest, Slope { right: -1, down: 1 }), 2);
+        assert_eq!(count_trees(&forest, Slope { right: 1, down: 1 }), 0);
+        assert_eq!(count_trees(&forest, Slope { right: -4, down: 1 }), 2);
+    }
+
+    #[test]
+    fn ranks_slopes_in_lowest_terms() {
+        let forest = Day3::parse(EXAMPLE).unwrap();
+        let ranked = rank_slopes(&forest, 2);
+        let slopes: Vec<_> = ranked
+            .iter()
+            .map(|(slope, _)| (slope.right, slope.down))
+            .collect();
+        assert_eq!(slopes.len(), 7);
+        assert!(!slopes.contains(&(2, 2)) && !slopes.contains(&(0, 2)));
+        assert!(ranked.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(best_slope(&forest, 2), ranked.first().copied());
+    }
+
+    #[test]
+    fn draws_the_example_path() {
+        let forest = Day3::parse(EXAMPLE).unwrap();
+        let drawing = draw_path(&forest, Slope { right: 3, down: 1 });
+        assert_eq!((drawing.width(), drawing.height()), (33, 11));
+        let lines: Vec<_> = drawing.to_string().lines().map(str::to_string).collect();
+        assert_eq!(lines[0], "O.##.........##.........##.......");
+        assert_eq!(lines[1], "#..O#...#..#...#...#..#...#...#..");
+        assert_eq!(lines[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+        let hits = drawing.iter().filter(|&&mark| mark == Mark::Hit).count();
+        assert_eq!(hits, count_trees(&forest, Slope { right: 3, down: 1 }));
+    }
+
+    #[test]
+    fn draws_leftward_paths() {
+        let forest = Day3::parse("...\n..#\n.#.").unwrap();
+        let drawing = draw_path(&forest, Slope { right: -1, down: 1 });
+        assert_eq!(drawing.to_string(), "...O..\n..X..#\n.X..#.");
+    }
+}
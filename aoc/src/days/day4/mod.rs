@@ -0,0 +1,501 @@
+use crate::records::parse_records;
+use crate::{ParseError, Solution};
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// The fields of one passport, in the order they were written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Passport {
+    pub fields: Vec<(String, String)>,
+}
+
+impl Passport {
+    pub fn new(input: &str, string_rep: &str) -> Result<Passport, ParseError> {
+        Passport::from_words(input, string_rep.split_whitespace())
+    }
+
+    /// A passport from its `<key>:<value>` fields, which must be slices of `input`.
+    pub fn from_words<'a>(
+        input: &str,
+        words: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Passport, ParseError> {
+        let fields = words
+            .into_iter()
+            .map(|field| match field.split_once(':') {
+                Some((key, value)) => Ok((key.to_string(), value.to_string())),
+                None => Err(ParseError::at(input, field, "expected \"<key>:<value>\"")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Passport { fields })
+    }
+
+    /// The value of the first field called `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn validate(&self, schema: &Schema) -> Validation {
+        schema.validate(self)
+    }
+
+    pub fn is_valid(&self, schema: &Schema) -> bool {
+        self.validate(schema).failures.is_empty()
+    }
+}
+
+/// How a field's value must look.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Validator {
+    /// Any value at all.
+    Any,
+    /// A four digit year from `min` to `max`.
+    Year {
+        min: u32,
+        max: u32,
+    },
+    /// A number directly followed by one of the units, within that unit's range.
+    Measure(Vec<Unit>),
+    /// `#` and six lowercase hex digits.
+    HexColour,
+    OneOf(Vec<String>),
+    /// Exactly this many digits, leading zeroes included.
+    Digits(usize),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Unit {
+    pub name: String,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Validator {
+    /// Why `value` doesn't fit, if it doesn't.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let fits = match self {
+            Validator::Any => true,
+            Validator::Year { min, max } => {
+                is_digits(value, 4) && (*min..=*max).contains(&value.parse().unwrap())
+            }
+            Validator::Measure(units) => {
+                let split = value
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(value.len());
+                let (number, unit) = value.split_at(split);
+                match (number.parse::<u32>(), units.iter().find(|u| u.name == unit)) {
+                    (Ok(number), Some(unit)) => (unit.min..=unit.max).contains(&number),
+                    _ => false,
+                }
+            }
+            Validator::HexColour => value.strip_prefix('#').is_some_and(|hex| {
+                hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+            }),
+            Validator::OneOf(choices) => choices.iter().any(|choice| choice == value),
+            Validator::Digits(count) => is_digits(value, *count),
+        };
+        if fits {
+            Ok(())
+        } else {
+            Err(format!("expected {}, found {:?}", self, value))
+        }
+    }
+}
+
+fn is_digits(value: &str, count: usize) -> bool {
+    value.len() == count && value.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Describes what the validator accepts.
+impl fmt::Display for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Validator::Any => write!(f, "anything"),
+            Validator::Year { min, max } => write!(f, "a year from {} to {}", min, max),
+            Validator::Measure(units) => {
+                let ranges: Vec<_> = units
+                    .iter()
+                    .map(|unit| format!("{} to {}{}", unit.min, unit.max, unit.name))
+                    .collect();
+                write!(f, "{}", ranges.join(" or "))
+            }
+            Validator::HexColour => write!(f, "a colour like #12ab3f"),
+            Validator::OneOf(choices) => write!(f, "one of {}", choices.join(", ")),
+            Validator::Digits(count) => write!(f, "{} digits", count),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FieldRule {
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default = "any")]
+    pub validator: Validator,
+}
+
+fn any() -> Validator {
+    Validator::Any
+}
+
+/// The fields a passport may have and what each must hold.
+///
+/// Besides being built in code, a schema can be read from a TOML file listing the fields like
+///
+/// ```toml
+/// [[fields]]
+/// name = "byr"
+/// required = true
+/// validator = { year = { min = 1920, max = 2002 } }
+///
+/// [[fields]]
+/// name = "hgt"
+/// required = true
+/// validator = { measure = [{ name = "cm", min = 150, max = 193 }] }
+///
+/// [[fields]]
+/// name = "hcl"
+/// validator = "hex_colour"
+/// ```
+///
+/// or from the same structure in JSON. Fields are optional unless marked required and accept
+/// anything without a validator; the others are `one_of = [...]` and `digits = <count>`.
+/// `strict.toml` next to this module spells out the part 2 rules.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+impl Schema {
+    pub fn new() -> Schema {
+        Schema::default()
+    }
+
+    pub fn required(self, name: &str, validator: Validator) -> Schema {
+        self.field(name, true, validator)
+    }
+
+    pub fn optional(self, name: &str, validator: Validator) -> Schema {
+        self.field(name, false, validator)
+    }
+
+    fn field(mut self, name: &str, required: bool, validator: Validator) -> Schema {
+        self.fields.push(FieldRule {
+            name: name.to_string(),
+            required,
+            validator,
+        });
+        self
+    }
+
+    /// Part 1: every field but `cid` has to be there, whatever it holds.
+    pub fn presence() -> Schema {
+        ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+            .iter()
+            .fold(Schema::new(), |schema, name| {
+                schema.required(name, Validator::Any)
+            })
+            .optional("cid", Validator::Any)
+    }
+
+    /// Part 2: the same fields, now with their values checked too.
+    pub fn strict() -> Schema {
+        let unit = |name: &str, min, max| Unit {
+            name: name.to_string(),
+            min,
+            max,
+        };
+        let year = |min, max| Validator::Year { min, max };
+        let eye_colours = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+        Schema::new()
+            .required("byr", year(1920, 2002))
+            .required("iyr", year(2010, 2020))
+            .required("eyr", year(2020, 2030))
+            .required(
+                "hgt",
+                Validator::Measure(vec![unit("cm", 150, 193), unit("in", 59, 76)]),
+            )
+            .required("hcl", Validator::HexColour)
+            .required(
+                "ecl",
+                Validator::OneOf(eye_colours.iter().map(|c| c.to_string()).collect()),
+            )
+            .required("pid", Validator::Digits(9))
+            .optional("cid", Validator::Any)
+    }
+
+    /// A schema built into the solution, by name: `presence` for part 1 or `strict` for part 2.
+    pub fn preset(name: &str) -> Option<Schema> {
+        match name {
+            "presence" => Some(Schema::presence()),
+            "strict" => Some(Schema::strict()),
+            _ => None,
+        }
+    }
+
+    pub fn from_toml(source: &str) -> Result<Schema, ParseError> {
+        toml::from_str(source).map_err(|err| {
+            let at = err
+                .span()
+                .map_or(&source[source.len()..], |span| &source[span]);
+            ParseError::at(source, at, err.message())
+        })
+    }
+
+    pub fn from_json(source: &str) -> Result<Schema, ParseError> {
+        serde_json::from_str(source).map_err(|err| {
+            let message = err.to_string();
+            // The message ends with where the error is, which the position already says.
+            let message = message
+                .rsplit_once(" at line ")
+                .map_or(message.as_str(), |(message, _)| message);
+            let at = if err.is_eof() {
+                &source[source.len()..]
+            } else {
+                json_fragment(source, err.line(), err.column())
+            };
+            ParseError::at(source, at, message)
+        })
+    }
+
+    /// Reads a schema file, as JSON if its name ends in `.json` and TOML otherwise.
+    pub fn load(path: &Path) -> Result<Schema, String> {
+        let source = fs::read_to_string(path)
+            .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+        let schema = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            Schema::from_json(&source)
+        } else {
+            Schema::from_toml(&source)
+        };
+        schema.map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn validate(&self, passport: &Passport) -> Validation {
+        let mut validation = Validation::default();
+        for rule in &self.fields {
+            let problem = match passport.get(&rule.name) {
+                Some(value) => rule.validator.check(value).err().map(Problem::Invalid),
+                None if rule.required => Some(Problem::Missing),
+                None => None,
+            };
+            if let Some(problem) = problem {
+                validation.failures.push(FieldFailure {
+                    field: rule.name.clone(),
+                    problem,
+                });
+            }
+        }
+        for (i, (key, _)) in passport.fields.iter().enumerate() {
+            if !self.fields.iter().any(|rule| &rule.name == key) {
+                validation.warnings.push(format!("unknown field {:?}", key));
+            } else if passport.fields[..i]
+                .iter()
+                .any(|(earlier, _)| earlier == key)
+            {
+                validation
+                    .warnings
+                    .push(format!("field {:?} given again and ignored", key));
+            }
+        }
+        validation
+    }
+}
+
+/// What's wrong with a passport: failures make it invalid, warnings don't.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Validation {
+    pub failures: Vec<FieldFailure>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldFailure {
+    pub field: String,
+    pub problem: Problem,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    Missing,
+    Invalid(String),
+}
+
+impl fmt::Display for FieldFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.problem {
+            Problem::Missing => write!(f, "{}: missing", self.field),
+            Problem::Invalid(reason) => write!(f, "{}: {}", self.field, reason),
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Passport>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Passport>, ParseError> {
+        parse_records(input, |record| Passport::from_words(input, record.words()))
+    }
+
+    fn part1(passports: &Vec<Passport>) -> usize {
+        count_valid(passports, &Schema::presence())
+    }
+
+    fn part2(passports: &Vec<Passport>) -> usize {
+        count_valid(passports, &Schema::strict())
+    }
+}
+
+/// The token serde_json's `line` and `column` (counted in bytes) point at, which for a
+/// string is its closing quote.
+fn json_fragment(source: &str, line: usize, column: usize) -> &str {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let mut offset = (line_start + column.saturating_sub(1)).min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    match source[offset..].chars().next() {
+        None => &source[offset..],
+        Some('"') => {
+            let opening = source[..offset].rfind('"').unwrap_or(offset);
+            &source[opening..=offset]
+        }
+        Some(c) => &source[offset..offset + c.len_utf8()],
+    }
+}
+
+fn count_valid(passports: &[Passport], schema: &Schema) -> usize {
+    passports.iter().filter(|p| p.is_valid(schema)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day4, Passport, Problem, Schema, Validator};
+    use crate::Solution;
+    use std::path::Path;
+
+    fn passport(fields: &str) -> Passport {
+        Passport::new(fields, fields).unwrap()
+    }
+
+    #[test]
+    fn validators() {
+        let hex = Validator::HexColour;
+        assert!(hex.check("#123abc").is_ok());
+        assert!(hex.check("#123abz").is_err());
+        assert!(hex.check("#12345g").is_err());
+        let pid = Validator::Digits(9);
+        assert!(pid.check("000000001").is_ok());
+        assert!(pid.check("+00000001").is_err());
+        assert!(pid.check("0123456789").is_err());
+        let year = Validator::Year {
+            min: 1920,
+            max: 2002,
+        };
+        assert!(year.check("2002").is_ok());
+        assert!(year.check("2003").is_err());
+        assert!(year.check("+200").is_err());
+        let strict = Schema::strict();
+        let hgt = &strict.fields[3].validator;
+        assert!(hgt.check("60in").is_ok());
+        assert!(hgt.check("190in").is_err());
+        assert!(hgt.check("190").is_err());
+    }
+
+    #[test]
+    fn reports_every_failing_field() {
+        let validation = passport("eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm")
+            .validate(&Schema::strict());
+        let failed: Vec<_> = validation
+            .failures
+            .iter()
+            .map(|failure| failure.field.as_str())
+            .collect();
+        assert_eq!(failed, ["byr", "iyr", "eyr", "hgt", "pid"]);
+        assert_eq!(validation.failures[0].problem, Problem::Missing);
+        assert_eq!(
+            validation.failures[2].to_string(),
+            "eyr: expected a year from 2020 to 2030, found \"1972\""
+        );
+        assert!(validation.warnings.is_empty());
+    }
+
+    #[test]
+    fn unknown_fields_are_warnings() {
+        let valid = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f";
+        let validation = passport(&format!("{} xyz:1 pid:1", valid)).validate(&Schema::strict());
+        assert!(validation.failures.is_empty());
+        assert_eq!(
+            validation.warnings,
+            [
+                "unknown field \"xyz\"",
+                "field \"pid\" given again and ignored"
+            ]
+        );
+    }
+
+    #[test]
+    fn strict_preset_matches_its_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days/day4/strict.toml");
+        assert_eq!(Schema::load(&path).unwrap(), Schema::strict());
+    }
+
+    #[test]
+    fn schema_files() {
+        let json = r#"{"fields": [
+            {"name": "cid", "required": true, "validator": {"digits": 3}},
+            {"name": "ecl", "validator": "any"}
+        ]}"#;
+        let schema = Schema::from_json(json).unwrap();
+        assert!(schema.fields[0].required && !schema.fields[1].required);
+        assert!(!passport("cid:12").is_valid(&schema));
+        assert!(passport("cid:123").is_valid(&schema));
+
+        let toml = "[[fields]]\nname = \"byr\"\nvalidator = { year = { min = 1 } }\n";
+        let error = Schema::from_toml(toml).unwrap_err();
+        assert_eq!(error.line, 3);
+        let error = Schema::from_json("{\"fields\": [{\"nme\": \"x\"}]}").unwrap_err();
+        assert_eq!((error.line, error.message.contains("nme")), (1, true));
+        assert_eq!((error.column, error.text.as_str()), (14, "\"nme\""));
+        let error = Schema::from_json("{\"fields\": [\n  {\"name\": 3}\n]}").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "3"));
+        let error = Schema::from_json("{\"fields\": [").unwrap_err();
+        assert!(error.to_string().ends_with("(found end of input)"));
+    }
+
+    #[test]
+    fn any_line_ending() {
+        let unix = "ecl:gry pid:860033327\nbyr:1937\n\niyr:2013 ecl:amb\n";
+        let windows = "ecl:gry pid:860033327\r\nbyr:1937\r\n  \r\niyr:2013 ecl:amb\r\n";
+        let fields = |input| {
+            Day4::parse(input)
+                .unwrap()
+                .iter()
+                .map(|passport| passport.fields.len())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(fields(unix), [3, 2]);
+        assert_eq!(fields(windows), [3, 2]);
+        let error = Day4::parse("byr:1937\r\n\r\necl amb").unwrap_err();
+        assert_eq!((error.record, error.line, error.column), (Some(2), 3, 1));
+    }
+}
@@ -0,0 +1,417 @@
+use crate::{Grid, ParseError, Solution};
+use std::fmt;
+use std::ops::Range;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = SeatMap;
+    type Part1 = HighestSeat;
+    type Part2 = YourSeat;
+
+    fn parse(input: &str) -> Result<SeatMap, ParseError> {
+        let plane = BoardingPass::STANDARD;
+        let seats = input
+            .lines()
+            .map(|line| plane.decode(line).map_err(|err| err.within(input, line)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(SeatMap::new(plane, &seats))
+    }
+
+    fn part1(seats: &SeatMap) -> HighestSeat {
+        match seats.last_taken() {
+            Some(seat_id) => HighestSeat::Found(seat_id),
+            None => HighestSeat::NoBoardingPasses,
+        }
+    }
+
+    fn part2(seats: &SeatMap) -> YourSeat {
+        match seats.gaps().as_slice() {
+            &[seat] => YourSeat::Found(seat),
+            gaps => YourSeat::NoSingleGap(gaps.to_vec()),
+        }
+    }
+}
+
+/// The highest seat id on a boarding pass, if there are any passes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HighestSeat {
+    Found(u64),
+    NoBoardingPasses,
+}
+
+impl fmt::Display for HighestSeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HighestSeat::Found(seat_id) => write!(f, "{}", seat_id),
+            HighestSeat::NoBoardingPasses => write!(f, "no boarding passes"),
+        }
+    }
+}
+
+/// The one empty seat with passengers in front and behind, if there's exactly one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum YourSeat {
+    Found(u64),
+    /// All the empty seats between passengers, of which there are none or several.
+    NoSingleGap(Vec<u64>),
+}
+
+impl fmt::Display for YourSeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YourSeat::Found(seat_id) => write!(f, "{}", seat_id),
+            YourSeat::NoSingleGap(gaps) if gaps.is_empty() => {
+                write!(f, "no single gap (no empty seats between passengers)")
+            }
+            YourSeat::NoSingleGap(gaps) => {
+                let gaps: Vec<_> = gaps.iter().map(|seat_id| seat_id.to_string()).collect();
+                write!(f, "no single gap (empty seats {})", gaps.join(", "))
+            }
+        }
+    }
+}
+
+/// The binary space partitioning code for a plane with `2^row_bits` rows of
+/// `2^column_bits` seats.
+///
+/// A code spells the row in `F`s (front, 0) and `B`s (back, 1), then the column in `L`s
+/// (left, 0) and `R`s (right, 1), most significant bit first. Read as one binary number,
+/// that's the seat's id.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoardingPass {
+    pub row_bits: u32,
+    pub column_bits: u32,
+}
+
+impl BoardingPass {
+    /// The plane from the puzzle: 128 rows of 8 seats, so codes like `FBFBBFFRLR`.
+    pub const STANDARD: BoardingPass = BoardingPass {
+        row_bits: 7,
+        column_bits: 3,
+    };
+
+    pub fn new(row_bits: u32, column_bits: u32) -> BoardingPass {
+        assert!(
+            row_bits + column_bits < 64,
+            "seat ids of {} bits don't fit in a u64",
+            row_bits + column_bits
+        );
+        BoardingPass {
+            row_bits,
+            column_bits,
+        }
+    }
+
+    /// How many characters a code has.
+    pub fn code_length(&self) -> usize {
+        (self.row_bits + self.column_bits) as usize
+    }
+
+    pub fn rows(&self) -> u64 {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> u64 {
+        1 << self.column_bits
+    }
+
+    pub fn seat_id(&self, row: u64, column: u64) -> u64 {
+        assert!(row < self.rows() && column < self.columns());
+        row << self.column_bits | column
+    }
+
+    /// The row and column of a seat.
+    pub fn seat(&self, seat_id: u64) -> (u64, u64) {
+        (seat_id >> self.column_bits, seat_id & (self.columns() - 1))
+    }
+
+    /// The seat id a code stands for. Errors are positioned within the code alone.
+    pub fn decode(&self, code: &str) -> Result<u64, ParseError> {
+        let mut seat_id = 0;
+        let mut count = 0;
+        for (i, c) in code.char_indices() {
+            let bit = match (count < self.row_bits as usize, c) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                (true, _) => {
+                    return Err(ParseError::at(
+                        code,
+                        &code[i..i + c.len_utf8()],
+                        "expected 'F' or 'B' in the row",
+                    ))
+                }
+                (false, _) if count < self.code_length() => {
+                    return Err(ParseError::at(
+                        code,
+                        &code[i..i + c.len_utf8()],
+                        "expected 'L' or 'R' in the column",
+                    ))
+                }
+                (false, _) => break,
+            };
+            seat_id = seat_id << 1 | bit;
+            count += 1;
+        }
+        if count != self.code_length() || code.chars().count() != self.code_length() {
+            return Err(ParseError::at(
+                code,
+                code,
+                format!("expected a {} character seat code", self.code_length()),
+            ));
+        }
+        Ok(seat_id)
+    }
+
+    /// The code for a seat, if the plane has it.
+    pub fn encode(&self, seat_id: u64) -> Option<String> {
+        if seat_id >> self.code_length() != 0 {
+            return None;
+        }
+        let code = (0..self.code_length())
+            .rev()
+            .enumerate()
+            .map(|(i, bit)| {
+                let set = seat_id >> bit & 1 == 1;
+                match (i < self.row_bits as usize, set) {
+                    (true, false) => 'F',
+                    (true, true) => 'B',
+                    (false, false) => 'L',
+                    (false, true) => 'R',
+                }
+            })
+            .collect();
+        Some(code)
+    }
+}
+
+/// Every seat on a plane, and how many boarding passes were issued for each.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatMap {
+    plane: BoardingPass,
+    passes: Grid<usize>,
+}
+
+impl SeatMap {
+    pub fn new(plane: BoardingPass, seat_ids: &[u64]) -> SeatMap {
+        let mut passes = Grid::from_fn(plane.columns() as usize, plane.rows() as usize, |_, _| 0);
+        for &seat_id in seat_ids {
+            let (row, column) = plane.seat(seat_id);
+            assert!(row < plane.rows(), "seat {} isn't on the plane", seat_id);
+            passes[(column as usize, row as usize)] += 1;
+        }
+        SeatMap { plane, passes }
+    }
+
+    pub fn plane(&self) -> BoardingPass {
+        self.plane
+    }
+
+    pub fn passes(&self, seat_id: u64) -> usize {
+        let (row, column) = self.plane.seat(seat_id);
+        self.passes[(column as usize, row as usize)]
+    }
+
+    /// Every seat with its number of passes, in seat id order.
+    pub fn seats(&self) -> impl Iterator<Item = (u64, usize)> + '_ {
+        self.passes
+            .iter()
+            .enumerate()
+            .map(|(seat_id, &passes)| (seat_id as u64, passes))
+    }
+
+    pub fn first_taken(&self) -> Option<u64> {
+        self.seats()
+            .find(|&(_, passes)| passes > 0)
+            .map(|(seat_id, _)| seat_id)
+    }
+
+    pub fn last_taken(&self) -> Option<u64> {
+        self.seats()
+            .filter(|&(_, passes)| passes > 0)
+            .map(|(seat_id, _)| seat_id)
+            .last()
+    }
+
+    /// Every seat nobody has a pass for.
+    pub fn missing(&self) -> Vec<u64> {
+        self.seats()
+            .filter(|&(_, passes)| passes == 0)
+            .map(|(seat_id, _)| seat_id)
+            .collect()
+    }
+
+    /// The empty seats in front of every passenger, which the whole plane is if it's empty.
+    pub fn missing_at_front(&self) -> Range<u64> {
+        0..self.first_taken().unwrap_or(self.end())
+    }
+
+    /// The empty seats behind every passenger.
+    pub fn missing_at_back(&self) -> Range<u64> {
+        self.last_taken().map_or(self.end(), |seat_id| seat_id + 1)..self.end()
+    }
+
+    /// The empty seats with passengers both in front and behind.
+    pub fn gaps(&self) -> Vec<u64> {
+        let (front, back) = (self.missing_at_front(), self.missing_at_back());
+        self.missing()
+            .into_iter()
+            .filter(|seat_id| !front.contains(seat_id) && !back.contains(seat_id))
+            .collect()
+    }
+
+    /// The seats more than one pass was issued for, with how many were.
+    pub fn duplicates(&self) -> Vec<(u64, usize)> {
+        self.seats().filter(|&(_, passes)| passes > 1).collect()
+    }
+
+    /// What each seat looks like in the seating chart, one row of the plane per row.
+    pub fn chart(&self) -> Grid<Seat> {
+        let (front, back) = (self.missing_at_front(), self.missing_at_back());
+        Grid::from_fn(self.passes.width(), self.passes.height(), |column, row| {
+            let seat_id = self.plane.seat_id(row as u64, column as u64);
+            match self.passes[(column, row)] {
+                0 if front.contains(&seat_id) || back.contains(&seat_id) => Seat::Absent,
+                0 => Seat::Empty,
+                1 => Seat::Taken,
+                passes => Seat::Duplicated(passes),
+            }
+        })
+    }
+
+    fn end(&self) -> u64 {
+        self.plane.rows() * self.plane.columns()
+    }
+}
+
+/// The seating chart: each row of the plane numbered, with an aisle down the middle.
+impl fmt::Display for SeatMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chart = self.chart();
+        let label = (chart.height() - 1).to_string().len();
+        let aisle = chart.width() / 2;
+        for row in 0..chart.height() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:>width$} ", row, width = label)?;
+            for (column, seat) in chart.row(row).iter().enumerate() {
+                if column == aisle && aisle > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", seat)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A seat in the seating chart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Seat {
+    /// Empty, and in front of or behind every passenger, so probably not there at all.
+    Absent,
+    /// Empty, between passengers.
+    Empty,
+    Taken,
+    /// Taken by this many passes.
+    Duplicated(usize),
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Seat::Absent => f.write_str("-"),
+            Seat::Empty => f.write_str("O"),
+            Seat::Taken => f.write_str("#"),
+            Seat::Duplicated(passes) if passes < 10 => write!(f, "{}", passes),
+            Seat::Duplicated(_) => f.write_str("+"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoardingPass, Day5, HighestSeat, Seat, SeatMap, YourSeat};
+    use crate::Solution;
+
+    #[test]
+    fn decodes_and_encodes() {
+        let pass = BoardingPass::STANDARD;
+        for &(code, seat_id, row, column) in &[
+            ("FBFBBFFRLR", 357, 44, 5),
+            ("BFFFBBFRRR", 567, 70, 7),
+            ("FFFBBBFRRR", 119, 14, 7),
+            ("BBFFBBFRLL", 820, 102, 4),
+        ] {
+            assert_eq!(pass.decode(code), Ok(seat_id));
+            assert_eq!(pass.seat(seat_id), (row, column));
+            assert_eq!(pass.seat_id(row, column), seat_id);
+            assert_eq!(pass.encode(seat_id).as_deref(), Some(code));
+        }
+        assert_eq!(pass.encode(1024), None);
+    }
+
+    #[test]
+    fn other_plane_sizes() {
+        let pass = BoardingPass::new(2, 4);
+        assert_eq!(pass.decode("BFRLLR"), Ok(0b10_1001));
+        assert_eq!(pass.encode(0b01_0110).as_deref(), Some("FBLRRL"));
+        assert!(pass.decode("FBFBBFFRLR").is_err());
+    }
+
+    #[test]
+    fn checks_each_position() {
+        let column = |code| BoardingPass::STANDARD.decode(code).unwrap_err().column;
+        assert_eq!(column("FBFRBFFRLR"), 4);
+        assert_eq!(column("FBFBBFFRBR"), 9);
+        assert_eq!(column("FBFBBFFRL"), 1);
+        assert_eq!(column("FBFBBFFRLRR"), 1);
+        let error = Day5::parse("FBFBBFFRLR\nBFFFBBFLRB").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+    }
+
+    #[test]
+    fn seat_map() {
+        let plane = BoardingPass::new(2, 2);
+        let seats = SeatMap::new(plane, &[3, 4, 4, 6, 8, 9, 12]);
+        assert_eq!(seats.missing(), [0, 1, 2, 5, 7, 10, 11, 13, 14, 15]);
+        assert_eq!(seats.missing_at_front(), 0..3);
+        assert_eq!(seats.missing_at_back(), 13..16);
+        assert_eq!(seats.gaps(), [5, 7, 10, 11]);
+        assert_eq!(seats.duplicates(), [(4, 2)]);
+        assert_eq!(seats.chart()[(0, 1)], Seat::Duplicated(2));
+        assert_eq!(seats.to_string(), "0 -- -#\n1 2O #O\n2 ## OO\n3 #- --");
+
+        let empty = SeatMap::new(plane, &[]);
+        assert_eq!(empty.missing_at_front(), 0..16);
+        assert!(empty.gaps().is_empty());
+    }
+
+    #[test]
+    fn needs_exactly_one_gap() {
+        let seats = Day5::parse("FBFBBFFRLR\nFBFBBFFRRL\n").unwrap();
+        assert_eq!(Day5::part2(&seats), YourSeat::NoSingleGap(vec![]));
+        assert_eq!(
+            Day5::part2(&seats).to_string(),
+            "no single gap (no empty seats between passengers)"
+        );
+        let seats = Day5::parse("FBFBBFFRLL\nFBFBBFFRRR\n").unwrap();
+        assert_eq!(
+            Day5::part2(&seats).to_string(),
+            "no single gap (empty seats 357, 358)"
+        );
+        let seats = Day5::parse("FBFBBFFRLR\nFBFBBFFRRR\n").unwrap();
+        assert_eq!(Day5::part2(&seats).to_string(), "358");
+    }
+
+    #[test]
+    fn answers_without_boarding_passes() {
+        let seats = Day5::parse("").unwrap();
+        assert_eq!(Day5::part1(&seats), HighestSeat::NoBoardingPasses);
+        assert_eq!(Day5::part1(&seats).to_string(), "no boarding passes");
+        assert_eq!(Day5::part2(&seats), YourSeat::NoSingleGap(vec![]));
+        let seats = Day5::parse("FBFBBFFRLR\n").unwrap();
+        assert_eq!(Day5::part1(&seats), HighestSeat::Found(357));
+    }
+}
@@ -0,0 +1,238 @@
+use crate::records::parse_records;
+use crate::{ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Group>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Group>, ParseError> {
+        parse_records(input, |record| {
+            let people = record
+                .lines
+                .iter()
+                .map(|line| Answers::parse(input, line))
+                .collect::<Result<_, _>>()?;
+            Ok(Group { people })
+        })
+    }
+
+    fn part1(groups: &Vec<Group>) -> usize {
+        count(groups, Query::Union)
+    }
+
+    fn part2(groups: &Vec<Group>) -> usize {
+        count(groups, Query::Intersection)
+    }
+}
+
+/// A set of questions, each named by a printable ASCII character, held as one bit apiece.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Answers(u128);
+
+impl Answers {
+    /// Every question there could be, from `'!'` to `'~'`.
+    pub const ALL: Answers = Answers((1 << (b'~' + 1)) - (1 << b'!'));
+
+    /// One person's line of answers, which must be a slice of `input`.
+    pub fn parse(input: &str, line: &str) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+        for (i, c) in line.char_indices() {
+            if !c.is_ascii_graphic() {
+                return Err(ParseError::at(
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    "expected a question (a printable ASCII character)",
+                ));
+            }
+            answers.insert(c);
+        }
+        Ok(answers)
+    }
+
+    pub fn insert(&mut self, question: char) {
+        assert!(question.is_ascii(), "no such question: {:?}", question);
+        self.0 |= 1 << question as u32;
+    }
+
+    pub fn contains(&self, question: char) -> bool {
+        question.is_ascii() && self.0 & 1 << question as u32 != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Answers) -> Answers {
+        Answers(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Answers) -> Answers {
+        Answers(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: Answers) -> Answers {
+        Answers(self.0 & !other.0)
+    }
+
+    /// The questions, in ASCII order.
+    pub fn iter(&self) -> impl Iterator<Item = char> + '_ {
+        (0..128u8)
+            .filter(move |&bit| self.0 & 1 << bit != 0)
+            .map(char::from)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter()
+            .try_for_each(|question| write!(f, "{}", question))
+    }
+}
+
+/// A group of people, each with the questions they answered "yes" to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Group {
+    pub people: Vec<Answers>,
+}
+
+impl Group {
+    /// The questions answered by at least `k` people in the group.
+    ///
+    /// `at_least[j]` holds the questions answered by `j` of the people seen so far, so each
+    /// person moves their questions up a level with a couple of bitwise operations, whatever
+    /// the number of questions.
+    pub fn answered_by_at_least(&self, k: usize) -> Answers {
+        if k > self.people.len() {
+            return Answers::default();
+        }
+        let mut at_least = vec![Answers::default(); k + 1];
+        at_least[0] = Answers::ALL;
+        for &person in &self.people {
+            for j in (1..=k).rev() {
+                at_least[j] = at_least[j].union(at_least[j - 1].intersection(person));
+            }
+        }
+        at_least[k]
+    }
+}
+
+/// Which questions of a group to count.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Query {
+    /// Answered by anyone.
+    Union,
+    /// Answered by everyone.
+    Intersection,
+    /// Answered by this many people or more.
+    AtLeast(usize),
+    /// Answered by one person alone.
+    ExactlyOne,
+}
+
+impl Query {
+    pub fn answers(&self, group: &Group) -> Answers {
+        match *self {
+            Query::Union => group
+                .people
+                .iter()
+                .fold(Answers::default(), |acc, &person| acc.union(person)),
+            Query::Intersection => group
+                .people
+                .iter()
+                .fold(Answers::ALL, |acc, &person| acc.intersection(person)),
+            Query::AtLeast(k) => group.answered_by_at_least(k),
+            Query::ExactlyOne => group
+                .answered_by_at_least(1)
+                .difference(group.answered_by_at_least(2)),
+        }
+    }
+}
+
+/// Spelt `union`, `intersection`, `at-least:<k>` or `exactly-one`.
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Query, String> {
+        match s.strip_prefix("at-least:") {
+            Some(k) => k
+                .parse()
+                .ok()
+                .filter(|&k| k > 0)
+                .map(Query::AtLeast)
+                .ok_or_else(|| format!("invalid number of people: {}", k)),
+            None => match s {
+                "union" => Ok(Query::Union),
+                "intersection" => Ok(Query::Intersection),
+                "exactly-one" => Ok(Query::ExactlyOne),
+                _ => Err(format!("no such query: {}", s)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Query::Union => f.write_str("union"),
+            Query::Intersection => f.write_str("intersection"),
+            Query::AtLeast(k) => write!(f, "at-least:{}", k),
+            Query::ExactlyOne => f.write_str("exactly-one"),
+        }
+    }
+}
+
+/// The sum over every group of how many questions `query` picks out.
+pub fn count(groups: &[Group], query: Query) -> usize {
+    groups.iter().map(|group| query.answers(group).len()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count, Answers, Day6, Query};
+    use crate::Solution;
+
+    const EXAMPLE: &str = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb\n";
+
+    #[test]
+    fn example() {
+        let groups = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(count(&groups, Query::Union), 11);
+        assert_eq!(count(&groups, Query::Intersection), 6);
+        assert_eq!(count(&groups, Query::AtLeast(1)), 11);
+        assert_eq!(count(&groups, Query::AtLeast(2)), 2);
+        assert_eq!(count(&groups, Query::AtLeast(5)), 0);
+        assert_eq!(count(&groups, Query::AtLeast(usize::MAX)), 0);
+        assert_eq!(count(&groups, Query::ExactlyOne), 9);
+    }
+
+    #[test]
+    fn any_printable_answer() {
+        let groups = Day6::parse("aB1\nB1?\n").unwrap();
+        assert_eq!(Query::Intersection.answers(&groups[0]).to_string(), "1B");
+        assert_eq!(Query::ExactlyOne.answers(&groups[0]).to_string(), "?a");
+        let error = Day6::parse("ab\n\ncdé").unwrap_err();
+        assert_eq!((error.record, error.line, error.column), (Some(2), 3, 3));
+        assert_eq!(Answers::ALL.len(), 94);
+        assert!(Answers::ALL.contains('!') && Answers::ALL.contains('~'));
+        assert!(!Answers::ALL.contains(' ') && !Answers::ALL.contains('\x7f'));
+    }
+
+    #[test]
+    fn query_names() {
+        for &query in &["union", "intersection", "at-least:3", "exactly-one"] {
+            assert_eq!(query.parse::<Query>().unwrap().to_string(), query);
+        }
+        assert!("at-least:x".parse::<Query>().is_err());
+        assert!("at-least:0".parse::<Query>().is_err());
+        assert!("some".parse::<Query>().is_err());
+    }
+}
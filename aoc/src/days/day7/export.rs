@@ -0,0 +1,204 @@
+use super::graph::{BagGraph, BagId};
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// A text format for drawing a `BagGraph`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Diagram {
+    /// Graphviz, as read by `dot`.
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+impl FromStr for Diagram {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Diagram, String> {
+        match s {
+            "dot" => Ok(Diagram::Dot),
+            "mermaid" => Ok(Diagram::Mermaid),
+            _ => Err(format!("no such diagram format: {}", s)),
+        }
+    }
+}
+
+/// How a colour relates to the one being highlighted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Role {
+    Unrelated,
+    Chosen,
+    /// Ends up holding the chosen colour.
+    Container,
+    /// Ends up inside the chosen colour.
+    Content,
+}
+
+impl BagGraph {
+    /// The graph in `diagram`'s format, each edge labelled with how many bags it stands for.
+    ///
+    /// With `highlight`, that colour, every bag that ends up holding it and every bag it ends
+    /// up holding are picked out, along with the edges between them that lead through it.
+    pub fn draw(&self, diagram: Diagram, highlight: Option<BagId>) -> String {
+        let roles = self.roles(highlight);
+        match diagram {
+            Diagram::Dot => self.to_dot(&roles),
+            Diagram::Mermaid => self.to_mermaid(&roles),
+        }
+    }
+
+    fn roles(&self, highlight: Option<BagId>) -> Vec<Role> {
+        let mut roles = vec![Role::Unrelated; self.len()];
+        if let Some(chosen) = highlight {
+            roles[chosen] = Role::Chosen;
+            let name = self.name(chosen);
+            for color in self.ancestors(name).unwrap_or_default() {
+                roles[self.id(color).unwrap()] = Role::Container;
+            }
+            for color in self.descendants(name).unwrap_or_default() {
+                roles[self.id(color).unwrap()] = Role::Content;
+            }
+        }
+        roles
+    }
+
+    /// Every edge, outer colour first, and whether it's on a path through the chosen colour.
+    fn edges<'a>(
+        &'a self,
+        roles: &'a [Role],
+    ) -> impl Iterator<Item = (BagId, BagId, usize, bool)> + 'a {
+        (0..self.len()).flat_map(move |outer| {
+            self.contents(outer).iter().map(move |&(inner, count)| {
+                let highlighted = matches!(
+                    (roles[outer], roles[inner]),
+                    (Role::Container, Role::Container)
+                        | (Role::Container, Role::Chosen)
+                        | (Role::Chosen, Role::Content)
+                        | (Role::Content, Role::Content)
+                );
+                (outer, inner, count, highlighted)
+            })
+        })
+    }
+
+    fn to_dot(&self, roles: &[Role]) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut dot = String::from("digraph bags {\n    node [shape=box, style=rounded];\n");
+        for (id, role) in roles.iter().enumerate() {
+            let style = match role {
+                Role::Unrelated => continue,
+                Role::Chosen => "style=\"rounded,filled\", fillcolor=gold",
+                Role::Container => "style=\"rounded,filled\", fillcolor=lightblue",
+                Role::Content => "style=\"rounded,filled\", fillcolor=palegreen",
+            };
+            writeln!(dot, "    {} [{}];", quote(self.name(id)), style).unwrap();
+        }
+        for (outer, inner, count, highlighted) in self.edges(roles) {
+            let style = if highlighted {
+                ", color=red, penwidth=2"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "    {} -> {} [label={}{}];",
+                quote(self.name(outer)),
+                quote(self.name(inner)),
+                count,
+                style
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn to_mermaid(&self, roles: &[Role]) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        for (id, role) in roles.iter().enumerate() {
+            let class = match role {
+                Role::Unrelated => "",
+                Role::Chosen => ":::chosen",
+                Role::Container => ":::container",
+                Role::Content => ":::content",
+            };
+            let label = self.name(id).replace('"', "#quot;");
+            writeln!(mermaid, "    bag{}[\"{}\"]{}", id, label, class).unwrap();
+        }
+        let mut highlighted_edges = Vec::new();
+        for (i, (outer, inner, count, highlighted)) in self.edges(roles).enumerate() {
+            writeln!(mermaid, "    bag{} -->|{}| bag{}", outer, count, inner).unwrap();
+            if highlighted {
+                highlighted_edges.push(i.to_string());
+            }
+        }
+        if roles.contains(&Role::Chosen) {
+            mermaid.push_str("    classDef chosen fill:gold\n");
+            mermaid.push_str("    classDef container fill:lightblue\n");
+            mermaid.push_str("    classDef content fill:palegreen\n");
+        }
+        if !highlighted_edges.is_empty() {
+            writeln!(
+                mermaid,
+                "    linkStyle {} stroke:red,stroke-width:2px",
+                highlighted_edges.join(",")
+            )
+            .unwrap();
+        }
+        mermaid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Diagram;
+    use crate::days::day7::BagGraph;
+
+    fn graph() -> BagGraph {
+        BagGraph::new(vec![
+            ("red", vec![("gold", 2), ("blue", 1)]),
+            ("gold", vec![("plum", 3)]),
+            ("blue", vec![("plum", 1)]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn dot() {
+        let graph = graph();
+        let dot = graph.draw(Diagram::Dot, None);
+        assert!(dot.starts_with("digraph bags {\n"));
+        assert!(dot.contains("    \"red\" -> \"gold\" [label=2];\n"));
+        assert!(!dot.contains("fillcolor"));
+
+        let dot = graph.draw(Diagram::Dot, graph.id("gold"));
+        assert!(dot.contains("    \"gold\" [style=\"rounded,filled\", fillcolor=gold];\n"));
+        assert!(dot.contains("    \"red\" -> \"gold\" [label=2, color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"gold\" -> \"plum\" [label=3, color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"red\" -> \"blue\" [label=1];\n"));
+        assert!(!dot.contains("    \"blue\" ["));
+    }
+
+    #[test]
+    fn mermaid() {
+        let graph = graph();
+        let mermaid = graph.draw(Diagram::Mermaid, graph.id("gold"));
+        assert_eq!(
+            mermaid,
+            "flowchart LR
+    bag0[\"red\"]:::container
+    bag1[\"gold\"]:::chosen
+    bag2[\"blue\"]
+    bag3[\"plum\"]:::content
+    bag0 -->|2| bag1
+    bag0 -->|1| bag2
+    bag1 -->|3| bag3
+    bag2 -->|1| bag3
+    classDef chosen fill:gold
+    classDef container fill:lightblue
+    classDef content fill:palegreen
+    linkStyle 0,2 stroke:red,stroke-width:2px
+"
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+/// A colour's index in its `BagGraph`.
+pub type BagId = usize;
+
+/// The bag rules: which bags, and how many of each, every colour of bag has to contain.
+///
+/// Colour names are interned, so the graph itself is plain indices. The rules must not make
+/// any bag contain itself, and with that checked up front, how many bags each colour holds
+/// in all is worked out once for every colour.
+#[derive(Clone, Debug)]
+pub struct BagGraph {
+    names: Vec<String>,
+    ids: HashMap<String, BagId>,
+    contents: Vec<Vec<(BagId, usize)>>,
+    containers: Vec<Vec<(BagId, usize)>>,
+    /// `None` where the count doesn't fit in a `usize`.
+    totals: Vec<Option<usize>>,
+}
+
+impl BagGraph {
+    /// The graph of `rules`, each a colour and the colours it contains with their counts.
+    ///
+    /// Colours only ever mentioned as contents are taken to contain nothing.
+    pub fn new<'a>(
+        rules: impl IntoIterator<Item = (&'a str, Vec<(&'a str, usize)>)>,
+    ) -> Result<BagGraph, Cycle> {
+        let mut graph = BagGraph {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            contents: Vec::new(),
+            containers: Vec::new(),
+            totals: Vec::new(),
+        };
+        for (color, contents) in rules {
+            let outer = graph.intern(color);
+            for (color, count) in contents {
+                let inner = graph.intern(color);
+                graph.contents[outer].push((inner, count));
+                graph.containers[inner].push((outer, count));
+            }
+        }
+
+        let order = graph.topological_order().map_err(|cycle| {
+            Cycle(
+                cycle
+                    .into_iter()
+                    .map(|id| graph.names[id].clone())
+                    .collect(),
+            )
+        })?;
+        // Counts can get astronomically large in a deep enough graph.
+        graph.totals = vec![None; graph.len()];
+        for id in order {
+            graph.totals[id] = graph.contents[id]
+                .iter()
+                .filter(|&&(_, count)| count > 0)
+                .try_fold(0usize, |total, &(inner, count)| {
+                    let each = graph.totals[inner]?.checked_add(1)?;
+                    total.checked_add(count.checked_mul(each)?)
+                });
+        }
+        Ok(graph)
+    }
+
+    /// How many colours there are.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, color: &str) -> Option<BagId> {
+        self.ids.get(color).copied()
+    }
+
+    pub fn name(&self, id: BagId) -> &str {
+        &self.names[id]
+    }
+
+    /// Every colour, in the order they were first mentioned.
+    pub fn colors(&self) -> impl Iterator<Item = &str> + '_ {
+        self.names.iter().map(|name| name.as_str())
+    }
+
+    /// The bags a bag of colour `id` directly contains, with how many of each.
+    pub fn contents(&self, id: BagId) -> &[(BagId, usize)] {
+        &self.contents[id]
+    }
+
+    /// The bags that directly contain a bag of colour `id`, with how many they contain.
+    pub fn containers(&self, id: BagId) -> &[(BagId, usize)] {
+        &self.containers[id]
+    }
+
+    /// Every colour of bag that ends up holding a `color` bag, nearest first.
+    pub fn ancestors(&self, color: &str) -> Option<Vec<&str>> {
+        let ids = self.reachable(self.id(color)?, &self.containers);
+        Some(ids.into_iter().map(|id| self.name(id)).collect())
+    }
+
+    /// Every colour of bag a `color` bag ends up holding, nearest first.
+    pub fn descendants(&self, color: &str) -> Option<Vec<&str>> {
+        let ids = self.reachable(self.id(color)?, &self.contents);
+        Some(ids.into_iter().map(|id| self.name(id)).collect())
+    }
+
+    /// How many bags a `color` bag holds in all, counting the ones inside other bags.
+    pub fn total_contents(&self, color: &str) -> Result<usize, CountError> {
+        let id = self
+            .id(color)
+            .ok_or_else(|| CountError::NoSuchColor(color.to_string()))?;
+        self.totals[id].ok_or_else(|| CountError::TooMany(color.to_string()))
+    }
+
+    fn intern(&mut self, color: &str) -> BagId {
+        if let Some(&id) = self.ids.get(color) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(color.to_string());
+        self.ids.insert(color.to_string(), id);
+        self.contents.push(Vec::new());
+        self.containers.push(Vec::new());
+        id
+    }
+
+    /// The colours other than `start` that following `edges` from it leads to.
+    fn reachable(&self, start: BagId, edges: &[Vec<(BagId, usize)>]) -> Vec<BagId> {
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        let mut found = Vec::new();
+        let mut queue = VecDeque::from(vec![start]);
+        while let Some(id) = queue.pop_front() {
+            for &(next, _) in &edges[id] {
+                if !seen[next] {
+                    seen[next] = true;
+                    found.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        found
+    }
+
+    /// Every colour after all the colours it contains, or the colours around a cycle if
+    /// there's no such order.
+    ///
+    /// This is a depth-first search with an explicit stack, as rule sets can nest far
+    /// deeper than the call stack would allow.
+    fn topological_order(&self) -> Result<Vec<BagId>, Vec<BagId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            OnStack,
+            Finished,
+        }
+
+        let mut state = vec![State::Unvisited; self.len()];
+        let mut order = Vec::with_capacity(self.len());
+        for root in 0..self.len() {
+            if state[root] != State::Unvisited {
+                continue;
+            }
+            state[root] = State::OnStack;
+            let mut stack = vec![(root, 0)];
+            while let Some(&mut (id, ref mut next)) = stack.last_mut() {
+                match self.contents[id].get(*next) {
+                    Some(&(inner, _)) => {
+                        *next += 1;
+                        match state[inner] {
+                            State::Unvisited => {
+                                state[inner] = State::OnStack;
+                                stack.push((inner, 0));
+                            }
+                            State::OnStack => {
+                                let start = stack.iter().position(|&(id, _)| id == inner);
+                                let cycle = stack[start.unwrap()..].iter().map(|&(id, _)| id);
+                                return Err(cycle.collect());
+                            }
+                            State::Finished => (),
+                        }
+                    }
+                    None => {
+                        state[id] = State::Finished;
+                        order.push(id);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+/// Colours of bag that would each have to contain the next, and the last the first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle(pub Vec<String>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bags would contain themselves: ", self.0[0])?;
+        for color in &self.0 {
+            write!(f, "{} -> ", color)?;
+        }
+        write!(f, "{}", self.0[0])
+    }
+}
+
+impl Error for Cycle {}
+
+/// Why a colour's bags couldn't be counted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CountError {
+    NoSuchColor(String),
+    /// More than a `usize` can hold.
+    TooMany(String),
+}
+
+impl fmt::Display for CountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountError::NoSuchColor(color) => write!(f, "no {} bags in the rules", color),
+            CountError::TooMany(color) => write!(f, "{} bags hold too many bags to count", color),
+        }
+    }
+}
+
+impl Error for CountError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{BagGraph, CountError, Cycle};
+
+    #[test]
+    fn counts_shared_contents_once() {
+        // Two bags a level, each holding one of both bags on the next level down, so there
+        // are 2^40 paths to the bottom but only 80 colours.
+        let names: Vec<_> = (0..=40)
+            .flat_map(|level| vec![format!("left {}", level), format!("right {}", level)])
+            .collect();
+        let rules = names
+            .chunks(2)
+            .zip(names.chunks(2).skip(1))
+            .flat_map(|(outer, inner)| {
+                outer.iter().map(move |color| {
+                    let contents = inner.iter().map(|inner| (inner.as_str(), 1)).collect();
+                    (color.as_str(), contents)
+                })
+            });
+        let graph = BagGraph::new(rules).unwrap();
+        assert_eq!(graph.total_contents("left 0"), Ok((1 << 41) - 2));
+        assert_eq!(graph.total_contents("right 40"), Ok(0));
+        assert_eq!(
+            graph.total_contents("mauve"),
+            Err(CountError::NoSuchColor("mauve".into()))
+        );
+        assert_eq!(graph.ancestors("left 1").unwrap().len(), 2);
+        assert_eq!(graph.ancestors("left 0"), Some(vec![]));
+        assert_eq!(
+            graph.descendants("left 39"),
+            Some(vec!["left 40", "right 40"])
+        );
+        assert_eq!(graph.ancestors("mauve"), None);
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let rules = vec![
+            ("red", vec![("blue", 1)]),
+            ("blue", vec![("green", 2), ("white", 1)]),
+            ("green", vec![("red", 1)]),
+        ];
+        let cycle = BagGraph::new(rules).unwrap_err();
+        assert_eq!(
+            cycle,
+            Cycle(vec!["red".into(), "blue".into(), "green".into()])
+        );
+        assert_eq!(
+            cycle.to_string(),
+            "red bags would contain themselves: red -> blue -> green -> red"
+        );
+        assert!(BagGraph::new(vec![("red", vec![("red", 1)])]).is_err());
+    }
+}
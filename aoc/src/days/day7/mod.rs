@@ -0,0 +1,164 @@
+use crate::{ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+mod export;
+mod graph;
+mod rule;
+
+pub use export::Diagram;
+pub use graph::{BagGraph, BagId, CountError, Cycle};
+pub use rule::{parse_rule, Rule};
+
+const SHINY_GOLD: &str = "shiny gold";
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = BagGraph;
+    type Part1 = BagCount;
+    type Part2 = BagCount;
+
+    fn parse(input: &str) -> Result<BagGraph, ParseError> {
+        let mut lines = HashMap::new();
+        let rules = input
+            .lines()
+            .map(|line| {
+                let rule = parse_rule(input, line)?;
+                if lines.insert(rule.color, line).is_some() {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("{} bags already have a rule", rule.color),
+                    ));
+                }
+                Ok((rule.color, rule.contents))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        BagGraph::new(rules)
+            .map_err(|cycle| ParseError::at(input, lines[cycle.0[0].as_str()], cycle.to_string()))
+    }
+
+    fn part1(graph: &BagGraph) -> BagCount {
+        match graph.ancestors(SHINY_GOLD) {
+            Some(ancestors) => BagCount::Found(ancestors.len()),
+            None => BagCount::NotCounted(CountError::NoSuchColor(SHINY_GOLD.to_string())),
+        }
+    }
+
+    fn part2(graph: &BagGraph) -> BagCount {
+        match graph.total_contents(SHINY_GOLD) {
+            Ok(total) => BagCount::Found(total),
+            Err(err) => BagCount::NotCounted(err),
+        }
+    }
+}
+
+/// A number of bags, or why the rules don't give one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BagCount {
+    Found(usize),
+    NotCounted(CountError),
+}
+
+impl fmt::Display for BagCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BagCount::Found(count) => write!(f, "{}", count),
+            BagCount::NotCounted(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BagCount, CountError, Day7};
+    use crate::Solution;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    #[test]
+    fn example() {
+        let graph = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&graph), BagCount::Found(4));
+        assert_eq!(Day7::part2(&graph), BagCount::Found(32));
+        assert_eq!(graph.total_contents("dark olive"), Ok(7));
+    }
+
+    #[test]
+    fn large_rule_set() {
+        // Every colour holds the next two, so the shiny gold bag at the bottom is inside all
+        // the others, each by astronomically many paths.
+        let colors = 100_000;
+        let mut input: String = (0..colors - 2)
+            .map(|i| {
+                format!(
+                    "pale c{} bags contain 1 pale c{} bag, 2 pale c{} bags.\n",
+                    i,
+                    i + 1,
+                    i + 2
+                )
+            })
+            .collect();
+        input.push_str(&format!(
+            "pale c{} bags contain 3 shiny gold bags.\n",
+            colors - 2
+        ));
+        input.push_str("shiny gold bags contain no other bags.\n");
+        let graph = Day7::parse(&input).unwrap();
+        assert_eq!(graph.len(), colors + 1);
+        assert_eq!(Day7::part1(&graph), BagCount::Found(colors - 1));
+        assert_eq!(Day7::part2(&graph), BagCount::Found(0));
+        assert_eq!(
+            graph.total_contents("pale c0"),
+            Err(CountError::TooMany("pale c0".into()))
+        );
+        assert_eq!(
+            graph.total_contents(&format!("pale c{}", colors - 3)),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn answers_without_shiny_gold_bags() {
+        let graph = Day7::parse("faded blue bags contain no other bags.").unwrap();
+        let no_such_color = BagCount::NotCounted(CountError::NoSuchColor("shiny gold".into()));
+        assert_eq!(Day7::part1(&graph), no_such_color);
+        assert_eq!(Day7::part2(&graph), no_such_color);
+        assert_eq!(
+            Day7::part2(&graph).to_string(),
+            "no shiny gold bags in the rules"
+        );
+        let graph = Day7::parse(
+            "a bags contain 9999999999 b bags.\n\
+             b bags contain 9999999999 shiny gold bags.\n\
+             shiny gold bags contain 9999999999 c bags.\n\
+             c bags contain 9999999999 d bags.",
+        )
+        .unwrap();
+        assert_eq!(Day7::part1(&graph), BagCount::Found(2));
+        assert_eq!(
+            Day7::part2(&graph).to_string(),
+            "shiny gold bags hold too many bags to count"
+        );
+    }
+
+    #[test]
+    fn reports_cycles_at_a_rule() {
+        let input = "a bags contain 1 b bag.\nb bags contain 2 c bags.\nc bags contain 1 b bag.";
+        let error = Day7::parse(input).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(error.message.starts_with("b bags would contain themselves"));
+        let error = Day7::parse("a bags contain 1 b bag.\na bags contain no other bags.");
+        assert_eq!(error.unwrap_err().line, 2);
+    }
+}
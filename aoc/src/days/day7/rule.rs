@@ -0,0 +1,184 @@
+use crate::{parse_at, ParseError};
+
+/// One line of the bag rules, borrowing its colour names from the input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule<'a> {
+    pub color: &'a str,
+    /// The colours a `color` bag contains, with how many of each.
+    pub contents: Vec<(&'a str, usize)>,
+}
+
+/// Parses a rule, which must be a slice of `source`:
+///
+/// ```text
+/// <color> bags contain <count> <color> bag(s), <count> <color> bag(s).
+/// <color> bags contain no other bags.
+/// ```
+///
+/// Colours are any words up to the next `bag` or `bags`, so one may well include "contain";
+/// the final full stop is optional, and either `bag` or `bags` is accepted wherever one goes.
+pub fn parse_rule<'a>(source: &str, line: &'a str) -> Result<Rule<'a>, ParseError> {
+    let mut words = Words { source, rest: line };
+    let (color, _) = words.color()?;
+    words.expect("contain")?;
+
+    let mut contents = Vec::new();
+    if words.keywords(&["no", "other"]) {
+        match words.next() {
+            Some("bag" | "bags" | "bag." | "bags.") => (),
+            Some(word) => return Err(words.error_at(word, "expected \"bags\"")),
+            None => return Err(words.error_at(words.rest, "expected \"bags\"")),
+        }
+    } else {
+        loop {
+            let count = match words.next() {
+                Some(count) => parse_at(source, count)?,
+                None => return Err(words.error_at(words.rest, "expected a number")),
+            };
+            let (color, punctuation) = words.color()?;
+            contents.push((color, count));
+            if punctuation != Some(',') {
+                break;
+            }
+        }
+    }
+
+    match words.next() {
+        None => Ok(Rule { color, contents }),
+        Some(word) => Err(words.error_at(word, "expected the end of the rule")),
+    }
+}
+
+/// The unparsed end of a rule, taken a word at a time.
+struct Words<'s, 'a> {
+    source: &'s str,
+    rest: &'a str,
+}
+
+impl<'s, 'a> Words<'s, 'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_start();
+        if self.rest.is_empty() {
+            return None;
+        }
+        let end = self
+            .rest
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(word)
+    }
+
+    /// Consumes `expected` if the rule continues with those words.
+    fn keywords(&mut self, expected: &[&str]) -> bool {
+        let mut ahead = Words { ..*self };
+        if expected.iter().all(|&word| ahead.next() == Some(word)) {
+            self.rest = ahead.rest;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        let at = self.rest.trim_start();
+        if self.keywords(&[expected]) {
+            Ok(())
+        } else {
+            let found = at.split_whitespace().next().unwrap_or(at);
+            Err(self.error_at(found, format!("expected \"{}\"", expected)))
+        }
+    }
+
+    /// A colour and the `bag` or `bags` after it, with the comma or full stop that
+    /// followed, if any.
+    fn color(&mut self) -> Result<(&'a str, Option<char>), ParseError> {
+        let start = self.rest.trim_start();
+        let mut end = None;
+        loop {
+            let word = match self.next() {
+                Some(word) => word,
+                None => return Err(self.error_at(self.rest, "expected \"bags\"")),
+            };
+            let (bag, punctuation) = match word.strip_suffix(|c| c == ',' || c == '.') {
+                Some(bag) => (bag, word.chars().last()),
+                None => (word, None),
+            };
+            if bag == "bag" || bag == "bags" {
+                let color = match end {
+                    Some(end) => &start[..end],
+                    None => return Err(self.error_at(word, "expected a colour")),
+                };
+                return Ok((color, punctuation));
+            }
+            end = Some(offset(start, word) + word.len());
+        }
+    }
+
+    fn error_at(&self, fragment: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.source, fragment, message)
+    }
+}
+
+/// Where `fragment` starts within `outer`, which it must be a slice of.
+fn offset(outer: &str, fragment: &str) -> usize {
+    fragment.as_ptr() as usize - outer.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rule, Rule};
+
+    fn parse(line: &str) -> Rule<'_> {
+        parse_rule(line, line).unwrap()
+    }
+
+    #[test]
+    fn parses_rules() {
+        assert_eq!(
+            parse("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+            Rule {
+                color: "light red",
+                contents: vec![("bright white", 1), ("muted yellow", 2)],
+            }
+        );
+        assert_eq!(
+            parse("faded blue bags contain no other bags."),
+            Rule {
+                color: "faded blue",
+                contents: vec![],
+            }
+        );
+        assert_eq!(
+            parse("dull contain bag contain 12 contain red bags"),
+            Rule {
+                color: "dull contain",
+                contents: vec![("contain red", 12)],
+            }
+        );
+    }
+
+    #[test]
+    fn reports_the_wrong_column() {
+        let column = |line: &str| parse_rule(line, line).unwrap_err().column;
+        assert_eq!(column("light red bags hold 1 bright white bag."), 16);
+        assert_eq!(column("light red bags contain bright white bag."), 24);
+        assert_eq!(
+            column("light red bags contain 1 bright white bag, 2 blue"),
+            50
+        );
+        assert_eq!(
+            column("light red bags contain 1 bright white bag. 2 blue bags."),
+            44
+        );
+        assert_eq!(column("bags contain no other bags."), 1);
+        assert_eq!(column("red bags contain 1 bags."), 20);
+        assert_eq!(column("red bags contain no other things."), 27);
+        let message = |line: &str| parse_rule(line, line).unwrap_err().to_string();
+        assert_eq!(
+            message("red bags contain 1 blue bag,"),
+            "line 1, column 29: expected a number (found end of input)"
+        );
+    }
+}
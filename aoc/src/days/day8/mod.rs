@@ -0,0 +1,183 @@
+use crate::{parse_at, ParseError, Solution};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    ACC,
+    JMP,
+    NOP,
+}
+
+#[derive(Clone, Debug)]
+pub struct Instruction {
+    pub called: bool,
+    pub op: Operation,
+    pub arg: i32,
+}
+
+impl Instruction {
+    pub fn from_string(input: &str, s: &str) -> Result<Instruction, ParseError> {
+        let mut tokens = s.split(" ");
+        let (operator, argument) = match tokens.next_tuple() {
+            Some(pair) => pair,
+            None => {
+                return Err(ParseError::at(
+                    input,
+                    s,
+                    "expected \"<operator> <argument>\"",
+                ))
+            }
+        };
+        if let Some(extra) = tokens.next() {
+            return Err(ParseError::at(
+                input,
+                extra,
+                "expected the end of the instruction",
+            ));
+        }
+        Ok(Instruction {
+            called: false,
+            op: match operator {
+                "acc" => Operation::ACC,
+                "jmp" => Operation::JMP,
+                "nop" => Operation::NOP,
+                _ => return Err(ParseError::at(input, operator, "unexpected operator")),
+            },
+            arg: parse_at(input, argument)?,
+        })
+    }
+
+    fn perform(self: &mut Instruction, mut acc: i32, mut ip: usize) -> (i32, usize) {
+        self.called = true;
+        match self.op {
+            Operation::ACC => {
+                acc += self.arg;
+                ip += 1
+            }
+            Operation::JMP => ip = (ip as i64 + self.arg as i64) as usize,
+            Operation::NOP => ip += 1,
+        }
+        (acc, ip)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        lines
+            .iter()
+            .enumerate()
+            .map(|(ip, line)| {
+                let instruction = Instruction::from_string(input, line)?;
+                if let Operation::JMP = instruction.op {
+                    // Jumping to just past the last instruction is how the program ends.
+                    let target = ip as i64 + instruction.arg as i64;
+                    if target < 0 || target > lines.len() as i64 {
+                        let argument = line.split(" ").nth(1).unwrap_or(line);
+                        return Err(ParseError::at(input, argument, "jump leaves the program"));
+                    }
+                }
+                Ok(instruction)
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> i32 {
+        acc_at_loop(instructions.clone())
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> i32 {
+        acc_after_repair(instructions.clone())
+    }
+}
+
+fn acc_at_loop(mut instructions: Vec<Instruction>) -> i32 {
+    let (mut acc, mut ip) = (0, 0);
+
+    while ip < instructions.len() && !instructions[ip].called {
+        let acc_ip = instructions[ip].perform(acc, ip);
+        acc = acc_ip.0;
+        ip = acc_ip.1;
+    }
+
+    acc
+}
+
+fn acc_after_repair(mut instructions: Vec<Instruction>) -> i32 {
+    let mut variants: Vec<(i32, usize, Vec<Instruction>)> = Vec::new();
+    let mut have_modified = false;
+    let (mut acc, mut ip) = (0, 0);
+
+    while ip != instructions.len() {
+        if ip >= instructions.len() || instructions[ip].called {
+            let snapshot = variants.pop().unwrap();
+            acc = snapshot.0;
+            ip = snapshot.1;
+            instructions = snapshot.2;
+            have_modified = true;
+        }
+
+        if !have_modified {
+            match instructions[ip].op {
+                Operation::JMP => {
+                    let mut snapshot = instructions.clone();
+                    snapshot[ip].op = Operation::NOP;
+                    variants.push((acc, ip, snapshot));
+                }
+                Operation::NOP => {
+                    let mut snapshot = instructions.clone();
+                    snapshot[ip].op = Operation::JMP;
+                    variants.push((acc, ip, snapshot));
+                }
+                _ => (),
+            }
+        }
+
+        let acc_ip = instructions[ip].perform(acc, ip);
+        acc = acc_ip.0;
+        ip = acc_ip.1;
+    }
+
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day8;
+    use crate::Solution;
+
+    #[test]
+    fn example() {
+        let instructions = Day8::parse(
+            "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n",
+        )
+        .unwrap();
+        assert_eq!(Day8::part1(&instructions), 5);
+        assert_eq!(Day8::part2(&instructions), 8);
+    }
+
+    #[test]
+    fn reports_malformed_instructions() {
+        let position = |input| {
+            let err = Day8::parse(input).unwrap_err();
+            (err.line, err.column)
+        };
+        assert_eq!(position("jmp -5"), (1, 5));
+        assert_eq!(position("nop +0\njmp +2"), (2, 5));
+        assert_eq!(position("acc +1 junk"), (1, 8));
+        assert_eq!(position("add +1"), (1, 1));
+        assert_eq!(position("acc"), (1, 1));
+    }
+
+    #[test]
+    fn stops_at_the_end_of_the_program() {
+        let instructions = Day8::parse("acc +1\njmp +1").unwrap();
+        assert_eq!(Day8::part1(&instructions), 1);
+    }
+}
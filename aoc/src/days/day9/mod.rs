@@ -1,3 +1,4 @@
+use crate::{parse_at, ParseError, Solution};
 use std::cmp::Ordering;
 
 const HISTORY_COUNT: usize = 25;
@@ -13,25 +14,39 @@ fn is_valid(number_pool: &[usize], target: usize) -> bool {
             }
         }
     }
-    return false;
+    false
 }
 
-fn main() {
-    let numbers: Vec<usize> = include_str!("input.txt")
-        .lines()
-        .map(|s| s.parse().unwrap())
-        .collect();
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        input.lines().map(|s| parse_at(input, s)).collect()
+    }
+
+    fn part1(numbers: &Vec<usize>) -> usize {
+        first_invalid(numbers)
+    }
+
+    fn part2(numbers: &Vec<usize>) -> usize {
+        find_weakness(numbers, first_invalid(numbers))
+    }
+}
+
+fn first_invalid(numbers: &[usize]) -> usize {
     let mut index = HISTORY_COUNT;
     while index < numbers.len() && is_valid(&numbers[index - HISTORY_COUNT..index], numbers[index])
     {
         index += 1
     }
-
-    println!("part1: {}", numbers[index]);
-    println!("part2: {}", part2(&numbers, numbers[index]))
+    numbers[index]
 }
 
-fn part2(numbers: &[usize], target: usize) -> usize {
+fn find_weakness(numbers: &[usize], target: usize) -> usize {
     let mut lowest = 0;
     let mut highest = 1;
     let mut total: usize = numbers[lowest..highest].iter().sum();
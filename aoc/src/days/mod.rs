@@ -0,0 +1,205 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::{ParseError, Parsed, Part, Solver};
+
+pub struct Day {
+    pub number: usize,
+    pub title: &'static str,
+    pub input: &'static str,
+    pub solver: &'static dyn Solver,
+}
+
+impl Day {
+    pub fn parts(&self) -> impl Iterator<Item = Part> {
+        vec![Part::One, Part::Two]
+            .into_iter()
+            .take(self.solver.parts())
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        self.solver
+            .parse(input)
+            .map_err(|err| err.in_day(self.number))
+    }
+}
+
+pub static DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        title: "Report Repair",
+        input: include_str!("day1/input.txt"),
+        solver: &day1::Day1,
+    },
+    Day {
+        number: 2,
+        title: "Password Philosophy",
+        input: include_str!("day2/input.txt"),
+        solver: &day2::Day2,
+    },
+    Day {
+        number: 3,
+        title: "Toboggan Trajectory",
+        input: include_str!("day3/input.txt"),
+        solver: &day3::Day3,
+    },
+    Day {
+        number: 4,
+        title: "Passport Processing",
+        input: include_str!("day4/input.txt"),
+        solver: &day4::Day4,
+    },
+    Day {
+        number: 5,
+        title: "Binary Boarding",
+        input: include_str!("day5/input.txt"),
+        solver: &day5::Day5,
+    },
+    Day {
+        number: 6,
+        title: "Custom Customs",
+        input: include_str!("day6/input.txt"),
+        solver: &day6::Day6,
+    },
+    Day {
+        number: 7,
+        title: "Handy Haversacks",
+        input: include_str!("day7/input.txt"),
+        solver: &day7::Day7,
+    },
+    Day {
+        number: 8,
+        title: "Handheld Halting",
+        input: include_str!("day8/input.txt"),
+        solver: &day8::Day8,
+    },
+    Day {
+        number: 9,
+        title: "Encoding Error",
+        input: include_str!("day9/input.txt"),
+        solver: &day9::Day9,
+    },
+    Day {
+        number: 10,
+        title: "Adapter Array",
+        input: include_str!("day10/input.txt"),
+        solver: &day10::Day10,
+    },
+    Day {
+        number: 11,
+        title: "Seating System",
+        input: include_str!("day11/input.txt"),
+        solver: &day11::Day11,
+    },
+    Day {
+        number: 12,
+        title: "Rain Risk",
+        input: include_str!("day12/input.txt"),
+        solver: &day12::Day12,
+    },
+    Day {
+        number: 13,
+        title: "Shuttle Search",
+        input: include_str!("day13/input.txt"),
+        solver: &day13::Day13,
+    },
+    Day {
+        number: 14,
+        title: "Docking Data",
+        input: include_str!("day14/input.txt"),
+        solver: &day14::Day14,
+    },
+    Day {
+        number: 15,
+        title: "Rambunctious Recitation",
+        input: include_str!("day15/input.txt"),
+        solver: &day15::Day15,
+    },
+    Day {
+        number: 16,
+        title: "Ticket Translation",
+        input: include_str!("day16/input.txt"),
+        solver: &day16::Day16,
+    },
+    Day {
+        number: 17,
+        title: "Conway Cubes",
+        input: include_str!("day17/input.txt"),
+        solver: &day17::Day17,
+    },
+    Day {
+        number: 18,
+        title: "Operation Order",
+        input: include_str!("day18/input.txt"),
+        solver: &day18::Day18,
+    },
+    Day {
+        number: 19,
+        title: "Monster Messages",
+        input: include_str!("day19/input.txt"),
+        solver: &day19::Day19,
+    },
+    Day {
+        number: 20,
+        title: "Jurassic Jigsaw",
+        input: include_str!("day20/input.txt"),
+        solver: &day20::Day20,
+    },
+    Day {
+        number: 21,
+        title: "Allergen Assessment",
+        input: include_str!("day21/input.txt"),
+        solver: &day21::Day21,
+    },
+    Day {
+        number: 22,
+        title: "Crab Combat",
+        input: include_str!("day22/input.txt"),
+        solver: &day22::Day22,
+    },
+    Day {
+        number: 23,
+        title: "Crab Cups",
+        input: include_str!("day23/input.txt"),
+        solver: &day23::Day23,
+    },
+    Day {
+        number: 24,
+        title: "Lobby Layout",
+        input: include_str!("day24/input.txt"),
+        solver: &day24::Day24,
+    },
+    Day {
+        number: 25,
+        title: "Combo Breaker",
+        input: include_str!("day25/input.txt"),
+        solver: &day25::Day25,
+    },
+];
+
+pub fn find(number: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,134 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, located by line and column (both starting at 1).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub day: Option<usize>,
+    /// Which blank-line separated record of the input the error is in, counting from 1.
+    pub record: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `fragment`, which must be a slice of `source`.
+    pub fn at(source: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let (line, column) = position(source, fragment);
+        ParseError {
+            day: None,
+            record: None,
+            line,
+            column,
+            text: fragment.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Re-expresses an error found while parsing `fragment` relative to the enclosing `source`.
+    pub fn within(self, source: &str, fragment: &str) -> ParseError {
+        let (line, column) = position(source, fragment);
+        ParseError {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+
+    pub fn in_record(self, record: usize) -> ParseError {
+        ParseError {
+            record: Some(record),
+            ..self
+        }
+    }
+
+    pub fn in_day(self, day: usize) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        if let Some(record) = self.record {
+            write!(f, "record {}, ", record)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if self.text.is_empty() {
+            write!(f, " (found end of input)")
+        } else {
+            write!(f, " (found {:?})", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `fragment`, a slice of `source`, reporting where it is if it isn't a valid `T`.
+pub fn parse_at<T: FromStr>(source: &str, fragment: &str) -> Result<T, ParseError> {
+    fragment
+        .parse()
+        .map_err(|_| ParseError::at(source, fragment, "expected a number"))
+}
+
+fn position(source: &str, fragment: &str) -> (usize, usize) {
+    let offset = (fragment.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|offset| *offset <= source.len())
+        .unwrap_or(0);
+    let before = &source[..offset];
+    // Lines may end in "\n", "\r\n" or a lone "\r".
+    let line = before
+        .match_indices(['\n', '\r'])
+        .filter(|&(i, ending)| !(ending == "\r" && source[i + 1..].starts_with('\n')))
+        .count()
+        + 1;
+    let column = before.len() - before.rfind(['\n', '\r']).map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn locates_fragment() {
+        let source = "first\nsecond line";
+        let error = ParseError::at(source, &source[13..], "bad");
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.text, "line");
+    }
+
+    #[test]
+    fn counts_any_line_ending() {
+        let source = "a\r\nb\rc\nd";
+        let error = ParseError::at(source, &source[7..], "bad");
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+
+    #[test]
+    fn rebases_nested_error() {
+        let source = "a\n\nrecord\nkey:value";
+        let record = &source[3..];
+        let inner = ParseError::at(record, &record[11..], "bad").within(source, record);
+        assert_eq!((inner.line, inner.column), (4, 5));
+        let first_line = ParseError::at(record, &record[2..], "bad").within(source, record);
+        assert_eq!((first_line.line, first_line.column), (3, 3));
+    }
+}
@@ -0,0 +1,356 @@
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Up, right, down and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The orthogonal directions and the four diagonals between them.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, indexed by `(x, y)` with `(0, 0)` at the top left.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid from `cells` listed row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len(), "cells don't fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut(usize, usize) -> T,
+    ) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Parses one cell per character, one row per line, as in most puzzle maps.
+    ///
+    /// `expected` describes the characters `cell` accepts, for the error about any it doesn't.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_lines(input, input.lines(), cell, expected)
+    }
+
+    /// Like `parse`, for rows that are already split into `lines`, all slices of `input`.
+    pub fn parse_lines<'a>(
+        input: &str,
+        lines: impl IntoIterator<Item = &'a str>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let found = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::at(input, found, expected))?);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected a row of {} cells", width),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid::new(width, height, cells)),
+            _ => Err(ParseError::at(input, input, "expected a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)` on a grid repeated endlessly in every direction.
+    pub fn wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// The position one step from `(x, y)` in each of `directions`, where that's still on the grid.
+    pub fn neighbors<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width, self.height);
+        directions
+            .iter()
+            .filter_map(move |&direction| step((width, height), (x, y), direction))
+    }
+
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(x, y, &ORTHOGONAL)
+    }
+
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(x, y, &ADJACENT)
+    }
+
+    /// The positions passed through moving from `(x, y)` in `direction` until leaving the grid,
+    /// not including `(x, y)` itself.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let size = (self.width, self.height);
+        let mut position = (x, y);
+        std::iter::from_fn(move || {
+            position = step(size, position, direction)?;
+            Some(position)
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Draws the grid as a binary PPM image, each cell a `scale` pixel square coloured by `color`.
+    pub fn to_ppm(&self, scale: usize, mut color: impl FnMut(&T) -> [u8; 3]) -> Vec<u8> {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        image.reserve(width * height * 3);
+        for y in 0..self.height {
+            let row: Vec<u8> = self
+                .row(y)
+                .iter()
+                .flat_map(|cell| {
+                    let rgb = color(cell);
+                    std::iter::repeat_n(rgb, scale).flatten()
+                })
+                .collect();
+            for _ in 0..scale {
+                image.extend_from_slice(&row);
+            }
+        }
+        image
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Turned clockwise by `quarter_turns` right angles.
+    pub fn rotated(&self, quarter_turns: usize) -> Grid<T> {
+        let (width, height) = (self.width, self.height);
+        match quarter_turns % 4 {
+            0 => self.clone(),
+            1 => Grid::from_fn(height, width, |x, y| self[(y, height - x - 1)].clone()),
+            2 => Grid::from_fn(width, height, |x, y| {
+                self[(width - x - 1, height - y - 1)].clone()
+            }),
+            _ => Grid::from_fn(height, width, |x, y| self[(width - y - 1, x)].clone()),
+        }
+    }
+
+    /// Flipped left to right.
+    pub fn mirrored(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - x - 1, y)].clone()
+        })
+    }
+
+    /// The `width` by `height` part of the grid with its top left at `(x, y)`.
+    pub fn subgrid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<T> {
+        Grid::from_fn(width, height, |i, j| self[(x + i, y + j)].clone())
+    }
+
+    /// Copies `other` over this grid with its top left at `(x, y)`.
+    pub fn paste(&mut self, x: usize, y: usize, other: &Grid<T>) {
+        for ((i, j), cell) in other.positions() {
+            self[(x + i, y + j)] = cell.clone();
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Draws the grid the way it would be parsed, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn step(
+    (width, height): (usize, usize),
+    (x, y): (usize, usize),
+    (dx, dy): (isize, isize),
+) -> Option<(usize, usize)> {
+    let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+    let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+    Some((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    fn example() -> Grid<i32> {
+        Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6])
+    }
+
+    #[test]
+    fn rotation1() {
+        let expected = Grid::new(2, 3, vec![4, 1, 5, 2, 6, 3]);
+        assert_eq!(example().rotated(1), expected);
+    }
+
+    #[test]
+    fn rotation2() {
+        let expected = Grid::new(3, 2, vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(example().rotated(2), expected);
+    }
+
+    #[test]
+    fn rotation3() {
+        let expected = Grid::new(2, 3, vec![3, 6, 2, 5, 1, 4]);
+        assert_eq!(example().rotated(3), expected);
+    }
+
+    #[test]
+    fn mirror() {
+        let expected = Grid::new(3, 2, vec![3, 2, 1, 6, 5, 4]);
+        assert_eq!(example().mirrored(), expected);
+    }
+
+    #[test]
+    fn indexing() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(*grid.wrapping(-1, 2), 3);
+    }
+
+    #[test]
+    fn neighbors_stay_on_the_grid() {
+        let grid = example();
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8(1, 1).count(), 5);
+        assert_eq!(grid.ray(0, 0, (1, 0)).collect::<Vec<_>>(), [(1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn draws_ppm() {
+        let grid = Grid::new(2, 1, vec![0u8, 255]);
+        let image = grid.to_ppm(2, |&level| [level; 3]);
+        let (header, pixels) = image.split_at(11);
+        assert_eq!(header, b"P6\n4 2\n255\n");
+        assert_eq!(pixels, [[0; 6], [255; 6], [0; 6], [255; 6]].concat());
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let input = "#.\n.#";
+        let grid = Grid::parse(input, Some, "anything").unwrap();
+        assert_eq!(grid.to_string(), input);
+        let error = Grid::parse("##\n#", Some, "anything").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
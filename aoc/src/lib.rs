@@ -0,0 +1,11 @@
+pub mod bench;
+pub mod days;
+mod error;
+pub mod grid;
+pub mod life;
+pub mod records;
+mod solution;
+
+pub use error::{parse_at, ParseError};
+pub use grid::Grid;
+pub use solution::{Parsed, Part, Solution, Solver};
@@ -0,0 +1,299 @@
+use crate::{Grid, ParseError};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+/// Which cells count as touching each other.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Neighborhood {
+    /// Every cell differing by at most one in each of this many coordinates.
+    Cubic(usize),
+    /// The six tiles around a hexagon, in axial `(q, r)` coordinates.
+    Hex,
+}
+
+impl Neighborhood {
+    pub fn dimensions(&self) -> usize {
+        match *self {
+            Neighborhood::Cubic(dimensions) => dimensions,
+            Neighborhood::Hex => 2,
+        }
+    }
+
+    /// How to get from a cell to each of its neighbours.
+    pub fn offsets(&self) -> Vec<Vec<i32>> {
+        match *self {
+            Neighborhood::Cubic(dimensions) => {
+                let mut offsets = vec![vec![]];
+                for _ in 0..dimensions {
+                    offsets = offsets
+                        .into_iter()
+                        .flat_map(|offset: Vec<i32>| {
+                            (-1..=1).map(move |step| {
+                                let mut longer = offset.clone();
+                                longer.push(step);
+                                longer
+                            })
+                        })
+                        .collect();
+                }
+                offsets.retain(|offset| offset.iter().any(|&step| step != 0));
+                offsets
+            }
+            Neighborhood::Hex => [[1, 0], [1, -1], [0, -1], [-1, 0], [-1, 1], [0, 1]]
+                .iter()
+                .map(|offset| offset.to_vec())
+                .collect(),
+        }
+    }
+}
+
+/// Spelt `cubic:<dimensions>` or `hex`.
+impl FromStr for Neighborhood {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Neighborhood, String> {
+        match s.strip_prefix("cubic:") {
+            Some(dimensions) => dimensions
+                .parse()
+                .ok()
+                .filter(|&dimensions| dimensions > 0)
+                .map(Neighborhood::Cubic)
+                .ok_or_else(|| format!("invalid number of dimensions: {}", dimensions)),
+            None if s == "hex" => Ok(Neighborhood::Hex),
+            None => Err(format!("no such neighbourhood: {}", s)),
+        }
+    }
+}
+
+/// The neighbour counts at which a dead cell comes alive and a live one stays alive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Rule {
+        assert!(
+            !birth.contains(&0),
+            "births without neighbours would fill all of space"
+        );
+        Rule {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+
+    pub fn next(&self, alive: bool, neighbors: usize) -> bool {
+        if alive {
+            self.survival.contains(&neighbors)
+        } else {
+            self.birth.contains(&neighbors)
+        }
+    }
+}
+
+/// The usual `B3/S23` notation: a digit per count, or commas between them once any count
+/// needs two digits, as in `B3/S2,3,13`.
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Rule, ParseError> {
+        let expected = "expected a rule like \"B3/S23\"";
+        let (birth, survival) = s
+            .split_once('/')
+            .ok_or_else(|| ParseError::at(s, s, expected))?;
+        let birth = birth
+            .strip_prefix('B')
+            .ok_or_else(|| ParseError::at(s, birth, expected))?;
+        let survival = survival
+            .strip_prefix('S')
+            .ok_or_else(|| ParseError::at(s, survival, expected))?;
+        let birth = parse_counts(s, birth)?;
+        if birth.contains(&0) {
+            return Err(ParseError::at(
+                s,
+                s,
+                "births without neighbours would fill all of space",
+            ));
+        }
+        Ok(Rule {
+            birth,
+            survival: parse_counts(s, survival)?,
+        })
+    }
+}
+
+fn parse_counts(source: &str, counts: &str) -> Result<Vec<usize>, ParseError> {
+    if counts.contains(',') {
+        counts
+            .split(',')
+            .map(|count| crate::parse_at(source, count))
+            .collect()
+    } else {
+        counts
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10).map(|count| count as usize).ok_or_else(|| {
+                    ParseError::at(source, &counts[i..i + c.len_utf8()], "expected a digit")
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |counts: &[usize]| {
+            let separator = if counts.iter().any(|&count| count > 9) {
+                ","
+            } else {
+                ""
+            };
+            counts
+                .iter()
+                .map(|count| count.to_string())
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        write!(f, "B{}/S{}", counts(&self.birth), counts(&self.survival))
+    }
+}
+
+/// An unbounded cellular automaton, storing only the cells that are alive.
+#[derive(Clone, Debug)]
+pub struct Life {
+    neighborhood: Neighborhood,
+    offsets: Vec<Vec<i32>>,
+    rule: Rule,
+    alive: HashSet<Vec<i32>>,
+}
+
+impl Life {
+    pub fn new(neighborhood: Neighborhood, rule: Rule) -> Life {
+        Life {
+            neighborhood,
+            offsets: neighborhood.offsets(),
+            rule,
+            alive: HashSet::new(),
+        }
+    }
+
+    /// Brings the `#` cells of a map to life on the plane of the first two coordinates.
+    pub fn plant(&mut self, map: &Grid<bool>) {
+        let dimensions = self.neighborhood.dimensions();
+        assert!(dimensions >= 2, "a map needs at least two dimensions");
+        for ((x, y), &alive) in map.positions() {
+            let mut cell = vec![0; dimensions];
+            cell[0] = x as i32;
+            cell[1] = y as i32;
+            self.set(cell, alive);
+        }
+    }
+
+    pub fn set(&mut self, cell: Vec<i32>, alive: bool) {
+        self.check(&cell);
+        if alive {
+            self.alive.insert(cell);
+        } else {
+            self.alive.remove(&cell);
+        }
+    }
+
+    pub fn toggle(&mut self, cell: Vec<i32>) {
+        let alive = self.is_alive(&cell);
+        self.set(cell, !alive);
+    }
+
+    pub fn is_alive(&self, cell: &[i32]) -> bool {
+        self.check(cell);
+        self.alive.contains(cell)
+    }
+
+    pub fn population(&self) -> usize {
+        self.alive.len()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &[i32]> + '_ {
+        self.alive.iter().map(|cell| cell.as_slice())
+    }
+
+    pub fn step(&mut self) {
+        let mut neighbors: HashMap<Vec<i32>, usize> = HashMap::new();
+        for cell in &self.alive {
+            for offset in &self.offsets {
+                let neighbor = cell.iter().zip(offset).map(|(a, b)| a + b).collect();
+                *neighbors.entry(neighbor).or_default() += 1;
+            }
+        }
+
+        // Live cells with no live neighbours never show up among the counts.
+        let mut next: HashSet<_> = self
+            .alive
+            .iter()
+            .filter(|&cell| !neighbors.contains_key(cell) && self.rule.next(true, 0))
+            .cloned()
+            .collect();
+        next.extend(
+            neighbors
+                .into_iter()
+                .filter(|(cell, count)| self.rule.next(self.alive.contains(cell), *count))
+                .map(|(cell, _)| cell),
+        );
+        self.alive = next;
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    fn check(&self, cell: &[i32]) {
+        assert_eq!(
+            cell.len(),
+            self.neighborhood.dimensions(),
+            "cell {:?} has the wrong number of coordinates",
+            cell
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Life, Neighborhood, Rule};
+    use crate::Grid;
+
+    #[test]
+    fn neighborhood_sizes() {
+        assert_eq!(Neighborhood::Cubic(2).offsets().len(), 8);
+        assert_eq!(Neighborhood::Cubic(4).offsets().len(), 80);
+        assert_eq!(Neighborhood::Hex.offsets().len(), 6);
+    }
+
+    #[test]
+    fn rule_notation() {
+        let rule: Rule = "B3/S23".parse().unwrap();
+        assert_eq!(rule, Rule::new(&[3], &[2, 3]));
+        assert_eq!(rule.to_string(), "B3/S23");
+        let wide: Rule = "B3/S2,13".parse().unwrap();
+        assert_eq!(wide.to_string(), "B3/S2,13");
+        assert_eq!("B3S23".parse::<Rule>().unwrap_err().column, 1);
+        assert_eq!("B3/S2x".parse::<Rule>().unwrap_err().column, 6);
+        assert!("B03/S23".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let map = Grid::parse(".#.\n.#.\n.#.", |c| Some(c == '#'), "").unwrap();
+        let mut life = Life::new(Neighborhood::Cubic(2), "B3/S23".parse().unwrap());
+        life.plant(&map);
+        life.step();
+        assert!(life.is_alive(&[0, 1]) && life.is_alive(&[2, 1]));
+        assert!(!life.is_alive(&[1, 0]));
+        life.step();
+        assert!(life.is_alive(&[1, 0]) && life.is_alive(&[1, 2]));
+        assert_eq!(life.population(), 3);
+    }
+}
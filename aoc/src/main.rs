@@ -1,3 +1,783 @@
+use aoc::bench::{self, Baseline};
+use aoc::days::day1::{self, Day1};
+use aoc::days::day2::{self, Day2};
+use aoc::days::day3::{self, Day3, Mark, Slope};
+use aoc::days::day4::{Day4, Schema};
+use aoc::days::day5::Day5;
+use aoc::days::day6::{self, Day6, Query};
+use aoc::days::day7::{Day7, Diagram};
+use aoc::days::{self, Day, DAYS};
+use aoc::life::{Life, Neighborhood, Rule};
+use aoc::{Grid, Part, Solution};
+use itertools::Itertools;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::Range;
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+const USAGE: &str = "\
+usage:
+    aoc run <day> [--part 1|2] [--format text|json] [<input>]
+    aoc run 1 --matches [--part 1|2] [--format text|json] [<input>]
+    aoc run 2 --policy <file> [--format text|json] [<input>]
+    aoc run 3 --slopes <n> | --best-slope <n> [--format text|json] [<input>]
+    aoc run 3 --path <right>,<down> [--ppm <file>] [<input>]
+    aoc run 4 --schema <file>|presence|strict [--format text|json] [<input>]
+    aoc run 5 --seat-map [--format text|json] [<input>]
+    aoc run 6 --query <query> [--format text|json] [<input>]
+    aoc run 7 --graph dot|mermaid [--highlight <color>] [<input>]
+    aoc run --all [--format text|json]
+    aoc bench [<day>] [--runs <n>] [--baseline <file>] [--save-baseline <file>]
+    aoc life <rule> [--neighborhood cubic:<n>|hex] [--generations <n>] <map>
+    aoc list
+
+<input> is a path to a puzzle input, or - to read it from stdin.
+Without it the input bundled with that day is used. --format json prints one
+{day, part, answer, elapsed_ms} record per line instead of text.
+
+--matches lists every pair (part 1) or triple (part 2) of day 1 entries summing
+to 2020 with their line numbers, rather than just the first one's product.
+--policy checks each day 2 password against the rules in <file>, one per line
+like \"at least 1 of digit\", and lists the ones each password breaks.
+--slopes ranks every day 3 slope moving at most <n> across (either way) and
+down by the trees it hits, and --best-slope shows just the one hitting fewest.
+--path draws the forest with the toboggan's path on it, X for trees hit and O
+for open ground, or writes it to <file> as a PPM image with --ppm.
+--schema checks day 4 passports against a TOML or JSON schema file, or one of
+the built-in presence (part 1) and strict (part 2) schemas, listing what's
+wrong with each passport.
+--seat-map charts the day 5 plane, # for taken seats, O for empty ones between
+passengers, - for empty ones in front of or behind them all, and a digit for
+seats with that many passes, then lists the empty seats and duplicate passes.
+--query counts the day 6 questions each group picks out with union (answered
+by anyone), intersection (by everyone), at-least:<k> (by k people or more) or
+exactly-one (by one person alone), and sums them.
+--graph prints the day 7 bag rules as a Graphviz or Mermaid diagram, with each
+edge labelled by its count. --highlight picks out <color>, the bags that end up
+holding it and the bags it ends up holding.
+
+bench times parsing and each part of every day, or just <day>, keeping the
+fastest of <n> runs. --baseline compares against timings saved earlier with
+--save-baseline.
+
+life plays a rule like B3/S23 on the '#' cells of <map> (a path, or - for
+stdin), in two dimensions unless --neighborhood says otherwise, and prints how
+many cells are alive after <n> generations (100 by default).";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Something to show about one day's input instead of its answers.
+enum Report<'a> {
+    Matches,
+    Policy(&'a str),
+    Slopes(usize),
+    BestSlope(usize),
+    Path(Slope),
+    Schema(&'a str),
+    SeatMap,
+    Query(Query),
+    Graph(Diagram),
+}
+
+impl Report<'_> {
+    fn day(&self) -> usize {
+        match self {
+            Report::Matches => 1,
+            Report::Policy(_) => 2,
+            Report::Slopes(_) | Report::BestSlope(_) | Report::Path(_) => 3,
+            Report::Schema(_) => 4,
+            Report::SeatMap => 5,
+            Report::Query(_) => 6,
+            Report::Graph(_) => 7,
+        }
+    }
+
+    fn flag(&self) -> &'static str {
+        match self {
+            Report::Matches => "--matches",
+            Report::Policy(_) => "--policy",
+            Report::Slopes(_) => "--slopes",
+            Report::BestSlope(_) => "--best-slope",
+            Report::Path(_) => "--path",
+            Report::Schema(_) => "--schema",
+            Report::SeatMap => "--seat-map",
+            Report::Query(_) => "--query",
+            Report::Graph(_) => "--graph",
+        }
+    }
+}
+
+enum Failure {
+    /// The command line didn't make sense, so the usage is worth showing.
+    Usage(String),
+    /// Something went wrong while running, and has already been reported.
+    Reported,
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Failure {
+        Failure::Usage(message)
+    }
+}
+
+impl From<&str> for Failure {
+    fn from(message: &str) -> Failure {
+        Failure::Usage(message.to_string())
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+
+    match dispatch(&args) {
+        Ok(()) => {}
+        Err(Failure::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+        Err(Failure::Reported) => process::exit(1),
+    }
+}
+
+fn dispatch(args: &[&str]) -> Result<(), Failure> {
+    match args {
+        ["list"] => {
+            list();
+            Ok(())
+        }
+        ["run", rest @ ..] => run(rest),
+        ["bench", rest @ ..] => bench(rest),
+        ["life", rest @ ..] => life(rest),
+        [] => Err("missing command".into()),
+        _ => Err(format!("unrecognised arguments: {}", args.join(" ")).into()),
+    }
+}
+
+fn run(args: &[&str]) -> Result<(), Failure> {
+    let mut all = false;
+    let mut part = None;
+    let mut format = Format::Text;
+    let mut report = None;
+    let mut ppm = None;
+    let mut highlight = None;
+    let mut positional = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--all" => all = true,
+            "--matches" => report = Some(Report::Matches),
+            "--policy" => {
+                report = Some(Report::Policy(iter.next().ok_or("--policy needs a file")?))
+            }
+            "--slopes" => {
+                let value = iter.next().ok_or("--slopes needs a bound")?;
+                report = Some(Report::Slopes(parse_bound(value)?));
+            }
+            "--best-slope" => {
+                let value = iter.next().ok_or("--best-slope needs a bound")?;
+                report = Some(Report::BestSlope(parse_bound(value)?));
+            }
+            "--path" => {
+                let value = iter.next().ok_or("--path needs a slope")?;
+                report = Some(Report::Path(parse_slope(value)?));
+            }
+            "--ppm" => ppm = Some(*iter.next().ok_or("--ppm needs a file")?),
+            "--schema" => {
+                report = Some(Report::Schema(iter.next().ok_or("--schema needs a file")?))
+            }
+            "--seat-map" => report = Some(Report::SeatMap),
+            "--query" => {
+                let value = iter.next().ok_or("--query needs a query")?;
+                report = Some(Report::Query(value.parse()?));
+            }
+            "--graph" => {
+                let value = iter.next().ok_or("--graph needs a format")?;
+                report = Some(Report::Graph(value.parse()?));
+            }
+            "--highlight" => highlight = Some(*iter.next().ok_or("--highlight needs a color")?),
+            "--part" => {
+                let value = iter.next().ok_or("--part needs a value")?;
+                part = Some(parse_part(value)?);
+            }
+            "--format" => {
+                let value = iter.next().ok_or("--format needs a value")?;
+                format = parse_format(value)?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag: {}", flag).into()),
+            _ => positional.push(arg),
+        }
+    }
+
+    if ppm.is_some() && !matches!(report, Some(Report::Path(_))) {
+        return Err("--ppm only goes with --path".into());
+    }
+    if highlight.is_some() && !matches!(report, Some(Report::Graph(_))) {
+        return Err("--highlight only goes with --graph".into());
+    }
+
+    match (all, positional.as_slice()) {
+        (true, []) if part.is_none() && report.is_none() => {
+            let failures = DAYS
+                .iter()
+                .filter(|day| run_day(day, day.input, None, format).is_err())
+                .count();
+            if failures > 0 {
+                Err(Failure::Reported)
+            } else {
+                Ok(())
+            }
+        }
+        (true, _) => Err("--all can't be combined with other arguments".into()),
+        (false, [day]) | (false, [day, _]) => {
+            let day = parse_day(day)?;
+            if let Some(wanted) = part {
+                if day.parts().all(|available| available != wanted) {
+                    return Err(format!("day {} has no part {}", day.number, wanted).into());
+                }
+            }
+            let input = load_input(day, positional.get(1).copied())?;
+            match report {
+                None => run_day(day, &input, part, format),
+                Some(report) if report.day() != day.number => {
+                    Err(format!("{} only applies to day {}", report.flag(), report.day()).into())
+                }
+                Some(Report::Matches) => list_matches(day, &input, part, format),
+                Some(Report::Policy(path)) => check_policies(day, &input, path, format),
+                Some(Report::Slopes(bound)) => show_slopes(day, &input, bound, None, format),
+                Some(Report::BestSlope(bound)) => show_slopes(day, &input, bound, Some(1), format),
+                Some(Report::Path(slope)) => draw_path(day, &input, slope, ppm),
+                Some(Report::Schema(schema)) => check_passports(day, &input, schema, format),
+                Some(Report::SeatMap) => show_seat_map(day, &input, format),
+                Some(Report::Query(query)) => count_answers(day, &input, query, format),
+                Some(Report::Graph(diagram)) => draw_bags(day, &input, diagram, highlight),
+            }
+        }
+        (false, []) => Err("missing day".into()),
+        (false, _) => Err(format!("unrecognised arguments: {}", positional.join(" ")).into()),
+    }
+}
+
+fn bench(args: &[&str]) -> Result<(), Failure> {
+    let mut runs = 1;
+    let mut baseline = None;
+    let mut save_baseline = None;
+    let mut positional = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--runs" => {
+                let value = iter.next().ok_or("--runs needs a value")?;
+                runs = value
+                    .parse()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or_else(|| format!("invalid number of runs: {}", value))?;
+            }
+            "--baseline" => baseline = Some(*iter.next().ok_or("--baseline needs a file")?),
+            "--save-baseline" => {
+                save_baseline = Some(*iter.next().ok_or("--save-baseline needs a file")?)
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag: {}", flag).into()),
+            _ => positional.push(arg),
+        }
+    }
+
+    let selected: Vec<&Day> = match positional.as_slice() {
+        [] => DAYS.iter().collect(),
+        [day] => vec![parse_day(day)?],
+        _ => return Err(format!("unrecognised arguments: {}", positional.join(" ")).into()),
+    };
+    let baseline = match baseline {
+        Some(path) => Some(Baseline::load(Path::new(path)).map_err(|err| {
+            eprintln!("error: couldn't read baseline {}: {}", path, err);
+            Failure::Reported
+        })?),
+        None => None,
+    };
+
+    let mut timings = Vec::new();
+    for day in selected {
+        eprintln!("timing day {}...", day.number);
+        match bench::time_day(day, day.input, runs) {
+            Ok(timing) => timings.push(timing),
+            Err(err) => {
+                eprintln!("error: {}", err);
+                return Err(Failure::Reported);
+            }
+        }
+    }
+    print!("{}", bench::table(&timings, baseline.as_ref()));
+
+    if let Some(path) = save_baseline {
+        Baseline::from_timings(&timings)
+            .save(Path::new(path))
+            .map_err(|err| {
+                eprintln!("error: couldn't write baseline {}: {}", path, err);
+                Failure::Reported
+            })?;
+    }
+    Ok(())
+}
+
+fn life(args: &[&str]) -> Result<(), Failure> {
+    let mut neighborhood = Neighborhood::Cubic(2);
+    let mut generations = 100;
+    let mut positional = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--neighborhood" => {
+                let value = iter.next().ok_or("--neighborhood needs a value")?;
+                neighborhood = value.parse()?;
+            }
+            "--generations" => {
+                let value = iter.next().ok_or("--generations needs a value")?;
+                generations = value
+                    .parse()
+                    .map_err(|_| format!("invalid number of generations: {}", value))?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag: {}", flag).into()),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (rule, source) = match positional.as_slice() {
+        [rule, source] => (rule, source),
+        [_] => return Err("missing map".into()),
+        [] => return Err("missing rule".into()),
+        _ => return Err(format!("unrecognised arguments: {}", positional.join(" ")).into()),
+    };
+    let rule: Rule = rule
+        .parse()
+        .map_err(|err: aoc::ParseError| format!("invalid rule {}: {}", rule, err.message))?;
+    if neighborhood.dimensions() < 2 {
+        return Err("a map needs at least two dimensions".into());
+    }
+    let input = read_input(source)?;
+    let map = Grid::parse(
+        &input,
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "expected '#' or '.'",
+    )
+    .map_err(|err| {
+        eprintln!("error: {}", err);
+        Failure::Reported
+    })?;
+
+    let mut life = Life::new(neighborhood, rule);
+    life.plant(&map);
+    life.run(generations);
+    println!("{}", life.population());
+    Ok(())
+}
+
+fn parse_day(day: &str) -> Result<&'static Day, String> {
+    day.parse()
+        .ok()
+        .and_then(days::find)
+        .ok_or_else(|| format!("no such day: {}", day))
+}
+
+fn parse_bound(bound: &str) -> Result<usize, String> {
+    bound
+        .parse()
+        .ok()
+        .filter(|&bound| bound > 0)
+        .ok_or_else(|| format!("invalid bound: {}", bound))
+}
+
+/// A slope written `<right>,<down>`.
+fn parse_slope(slope: &str) -> Result<Slope, String> {
+    slope
+        .split_once(',')
+        .and_then(|(right, down)| Some((right.parse().ok()?, down.parse().ok()?)))
+        .filter(|&(_, down)| down > 0)
+        .map(|(right, down)| Slope { right, down })
+        .ok_or_else(|| format!("invalid slope: {}", slope))
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    match part {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("no such part: {}", part)),
+    }
+}
+
+fn parse_format(format: &str) -> Result<Format, String> {
+    match format {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(format!("no such format: {}", format)),
+    }
+}
+
+fn load_input(day: &Day, source: Option<&str>) -> Result<String, Failure> {
+    match source {
+        None => Ok(day.input.to_string()),
+        Some(source) => read_input(source),
+    }
+}
+
+/// Reads the file at `source`, or stdin if it's `-`.
+fn read_input(source: &str) -> Result<String, Failure> {
+    let (name, loaded) = match source {
+        "-" => {
+            let mut input = String::new();
+            (
+                "stdin",
+                io::stdin().read_to_string(&mut input).map(|_| input),
+            )
+        }
+        path => (path, fs::read_to_string(path)),
+    };
+    loaded.map_err(|err| {
+        eprintln!("error: couldn't read {}: {}", name, err);
+        Failure::Reported
+    })
+}
+
+fn list() {
+    for day in DAYS.iter() {
+        println!("day{:<3} {}", day.number, day.title);
+    }
+}
+
+fn run_day(day: &Day, input: &str, part: Option<Part>, format: Format) -> Result<(), Failure> {
+    if let Format::Text = format {
+        println!("Day {}: {}", day.number, day.title);
+    }
+    let parsed = day.parse(input).map_err(|err| {
+        eprintln!("error: {}", err);
+        Failure::Reported
+    })?;
+    for current in day.parts() {
+        if part.is_none_or(|wanted| wanted == current) {
+            let start = Instant::now();
+            let answer = parsed.solve(current);
+            let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+            match format {
+                Format::Text => println!("part{}: {}", current, answer),
+                Format::Json => println!(
+                    "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3}}}",
+                    day.number,
+                    current,
+                    json_string(&answer),
+                    elapsed_ms
+                ),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn list_matches(day: &Day, input: &str, part: Option<Part>, format: Format) -> Result<(), Failure> {
+    if let Format::Text = format {
+        println!("Day {}: {}", day.number, day.title);
+    }
+    let report = Day1::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+    for current in day.parts() {
+        if part.is_some_and(|wanted| wanted != current) {
+            continue;
+        }
+        let found: Vec<_> = day1::matches(&report, current).collect();
+        match format {
+            Format::Text => {
+                let noun = if found.len() == 1 { "match" } else { "matches" };
+                println!("part{}: {} {}", current, found.len(), noun);
+                for found in &found {
+                    let entries: Vec<_> = found
+                        .entries
+                        .iter()
+                        .zip(&found.lines)
+                        .map(|(entry, line)| format!("{} (line {})", entry, line))
+                        .collect();
+                    let product = found
+                        .product
+                        .map_or("too large".to_string(), |product| product.to_string());
+                    println!("    {}, product {}", entries.join(" + "), product);
+                }
+            }
+            Format::Json => {
+                for found in &found {
+                    println!(
+                        "{{\"day\":{},\"part\":{},\"lines\":[{}],\"entries\":[{}],\"product\":{}}}",
+                        day.number,
+                        current,
+                        found.lines.iter().join(","),
+                        found.entries.iter().join(","),
+                        found
+                            .product
+                            .map_or("null".to_string(), |product| product.to_string())
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_policies(day: &Day, input: &str, path: &str, format: Format) -> Result<(), Failure> {
+    if let Format::Text = format {
+        println!("Day {}: {}", day.number, day.title);
+    }
+    let rules = read_input(path)?;
+    let policies = day2::parse_policies(&rules).map_err(|err| {
+        eprintln!("error: {}: {}", path, err);
+        Failure::Reported
+    })?;
+    let entries = Day2::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+
+    let mut valid = 0;
+    for (i, entry) in entries.iter().enumerate() {
+        let password = &entry.password;
+        let broken = day2::violations(&policies, password);
+        if broken.is_empty() {
+            valid += 1;
+            continue;
+        }
+        let rules = broken.iter().map(|policy| policy.to_string());
+        match format {
+            Format::Text => println!(
+                "line {}: {} breaks {}",
+                i + 1,
+                password,
+                rules.map(|rule| format!("\"{}\"", rule)).join(", ")
+            ),
+            Format::Json => println!(
+                "{{\"day\":{},\"line\":{},\"password\":{},\"violations\":[{}]}}",
+                day.number,
+                i + 1,
+                json_string(password),
+                rules.map(|rule| json_string(&rule)).join(",")
+            ),
+        }
+    }
+    if let Format::Text = format {
+        println!("valid: {} of {}", valid, entries.len());
+    }
+    Ok(())
+}
+
+fn show_slopes(
+    day: &Day,
+    input: &str,
+    bound: usize,
+    limit: Option<usize>,
+    format: Format,
+) -> Result<(), Failure> {
+    if let Format::Text = format {
+        println!("Day {}: {}", day.number, day.title);
+    }
+    let forest = Day3::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+    let ranked = day3::rank_slopes(&forest, bound);
+    for (slope, trees) in ranked.iter().take(limit.unwrap_or(ranked.len())) {
+        match format {
+            Format::Text => println!("{}: {} trees", slope, trees),
+            Format::Json => println!(
+                "{{\"day\":{},\"right\":{},\"down\":{},\"trees\":{}}}",
+                day.number, slope.right, slope.down, trees
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn draw_path(day: &Day, input: &str, slope: Slope, ppm: Option<&str>) -> Result<(), Failure> {
+    let forest = Day3::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+    let drawing = day3::draw_path(&forest, slope);
+    match ppm {
+        None => println!("{}", drawing),
+        Some(path) => fs::write(path, drawing.to_ppm(4, Mark::color)).map_err(|err| {
+            eprintln!("error: couldn't write {}: {}", path, err);
+            Failure::Reported
+        })?,
+    }
+    Ok(())
+}
+
+fn check_passports(day: &Day, input: &str, schema: &str, format: Format) -> Result<(), Failure> {
+    if let Format::Text = format {
+        println!("Day {}: {}", day.number, day.title);
+    }
+    let schema = match Schema::preset(schema) {
+        Some(preset) => preset,
+        None => Schema::load(Path::new(schema)).map_err(|err| {
+            eprintln!("error: {}", err);
+            Failure::Reported
+        })?,
+    };
+    let passports = Day4::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+
+    let mut valid = 0;
+    for (i, passport) in passports.iter().enumerate() {
+        let validation = passport.validate(&schema);
+        if validation.failures.is_empty() {
+            valid += 1;
+        }
+        let failures = validation
+            .failures
+            .iter()
+            .map(|failure| failure.to_string());
+        match format {
+            Format::Text => {
+                for problem in failures.chain(
+                    validation
+                        .warnings
+                        .iter()
+                        .map(|warning| format!("warning: {}", warning)),
+                ) {
+                    println!("passport {}: {}", i + 1, problem);
+                }
+            }
+            Format::Json => println!(
+                "{{\"day\":{},\"passport\":{},\"failures\":[{}],\"warnings\":[{}]}}",
+                day.number,
+                i + 1,
+                failures.map(|failure| json_string(&failure)).join(","),
+                validation.warnings.iter().map(|w| json_string(w)).join(",")
+            ),
+        }
+    }
+    if let Format::Text = format {
+        println!("valid: {} of {}", valid, passports.len());
+    }
+    Ok(())
+}
+
+fn show_seat_map(day: &Day, input: &str, format: Format) -> Result<(), Failure> {
+    let seats = Day5::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+    let (front, back) = (seats.missing_at_front(), seats.missing_at_back());
+    let gaps = seats.gaps();
+    let duplicates = seats.duplicates();
+    match format {
+        Format::Text => {
+            println!("Day {}: {}", day.number, day.title);
+            println!("{}", seats);
+            let code = |seat_id| seats.plane().encode(seat_id).unwrap();
+            let range = |seats: &Range<u64>| match seats.end - seats.start {
+                0 => "none".to_string(),
+                1 => format!("{} ({})", seats.start, code(seats.start)),
+                count => format!("{}-{} ({} seats)", seats.start, seats.end - 1, count),
+            };
+            println!("missing at the front: {}", range(&front));
+            println!("missing at the back: {}", range(&back));
+            for seat_id in &gaps {
+                println!("empty between passengers: {} ({})", seat_id, code(*seat_id));
+            }
+            for (seat_id, passes) in &duplicates {
+                println!(
+                    "duplicate: {} ({}) has {} passes",
+                    seat_id,
+                    code(*seat_id),
+                    passes
+                );
+            }
+        }
+        Format::Json => println!(
+            "{{\"day\":{},\"front\":[{},{}],\"back\":[{},{}],\"gaps\":[{}],\"duplicates\":[{}]}}",
+            day.number,
+            front.start,
+            front.end,
+            back.start,
+            back.end,
+            gaps.iter().join(","),
+            duplicates
+                .iter()
+                .map(|(seat_id, passes)| format!("{{\"seat\":{},\"passes\":{}}}", seat_id, passes))
+                .join(",")
+        ),
+    }
+    Ok(())
+}
+
+fn count_answers(day: &Day, input: &str, query: Query, format: Format) -> Result<(), Failure> {
+    let groups = Day6::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+    let count = day6::count(&groups, query);
+    match format {
+        Format::Text => {
+            println!("Day {}: {}", day.number, day.title);
+            println!("{}: {}", query, count);
+        }
+        Format::Json => println!(
+            "{{\"day\":{},\"query\":{},\"count\":{}}}",
+            day.number,
+            json_string(&query.to_string()),
+            count
+        ),
+    }
+    Ok(())
+}
+
+fn draw_bags(
+    day: &Day,
+    input: &str,
+    diagram: Diagram,
+    highlight: Option<&str>,
+) -> Result<(), Failure> {
+    let graph = Day7::parse(input).map_err(|err| {
+        eprintln!("error: {}", err.in_day(day.number));
+        Failure::Reported
+    })?;
+    let highlight = match highlight {
+        None => None,
+        Some(color) => Some(graph.id(color).ok_or_else(|| {
+            eprintln!("error: no {} bags in the rules", color);
+            Failure::Reported
+        })?),
+    };
+    print!("{}", graph.draw(diagram, highlight));
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
 }
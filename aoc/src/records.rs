@@ -0,0 +1,120 @@
+use crate::ParseError;
+
+/// A group of consecutive non-blank lines, as several days' inputs are laid out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record<'a> {
+    /// Which record this is, counting from 1.
+    pub number: usize,
+    /// The lines without their line endings or trailing whitespace, as slices of the input.
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Record<'a> {
+    /// Every whitespace separated word on every line.
+    pub fn words(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.lines.iter().flat_map(|line| line.split_whitespace())
+    }
+}
+
+/// The records of `input`, read as they're asked for.
+///
+/// Lines may end in `\n`, `\r\n` or `\r`, and any number of lines holding nothing but
+/// whitespace separate records.
+pub fn records(input: &str) -> Records<'_> {
+    Records {
+        rest: input,
+        number: 0,
+    }
+}
+
+/// Parses every record of `input` with `parse`, marking any error with its record's number.
+pub fn parse_records<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&Record<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    records(input)
+        .map(|record| parse(&record).map_err(|err| err.in_record(record.number)))
+        .collect()
+}
+
+pub struct Records<'a> {
+    rest: &'a str,
+    number: usize,
+}
+
+impl<'a> Records<'a> {
+    fn next_line(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let end = self.rest.find(['\n', '\r']).unwrap_or(self.rest.len());
+        let line = &self.rest[..end];
+        let rest = &self.rest[end..];
+        self.rest = rest
+            .strip_prefix("\r\n")
+            .or_else(|| rest.strip_prefix(['\n', '\r']))
+            .unwrap_or(rest);
+        Some(line.trim_end())
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Record<'a>> {
+        let mut lines = Vec::new();
+        while let Some(line) = self.next_line() {
+            if !line.is_empty() {
+                lines.push(line);
+            } else if !lines.is_empty() {
+                break;
+            }
+        }
+        if lines.is_empty() {
+            return None;
+        }
+        self.number += 1;
+        Some(Record {
+            number: self.number,
+            lines,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_records, records};
+    use crate::{parse_at, ParseError};
+
+    fn lines(input: &str) -> Vec<Vec<&str>> {
+        records(input).map(|record| record.lines).collect()
+    }
+
+    #[test]
+    fn splits_on_blank_lines() {
+        let expected = [vec!["a", "b c"], vec!["d"]];
+        assert_eq!(lines("a\nb c\n\nd\n"), expected);
+        assert_eq!(lines("a\r\nb c\r\n\r\nd"), expected);
+        assert_eq!(lines("a\rb c\r\rd\r"), expected);
+        assert_eq!(lines("\n\na  \nb c\t\n   \n\t\n\nd\n\n"), expected);
+        assert!(lines("").is_empty());
+    }
+
+    #[test]
+    fn numbers_records_in_errors() {
+        let input = "1\n2\n\n3\r\nx\r\n\r\n4";
+        let error = parse_records(input, |record| {
+            record
+                .lines
+                .iter()
+                .map(|line| parse_at(input, line))
+                .collect::<Result<Vec<u32>, ParseError>>()
+        })
+        .unwrap_err();
+        assert_eq!((error.record, error.line, error.column), (Some(2), 5, 1));
+        assert_eq!(
+            error.to_string(),
+            "record 2, line 5, column 1: expected a number (found \"x\")"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::ParseError;
+use std::fmt::{self, Display};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Part::One => "1",
+            Part::Two => "2",
+        })
+    }
+}
+
+/// A single day's puzzle: parse the raw input into a model, then answer each part from it.
+pub trait Solution {
+    /// How many parts the puzzle has. Only day 25 has fewer than two.
+    const PARTS: usize = 2;
+
+    type Input: 'static;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Object-safe view of a [`Solution`], so days with different models can share a registry.
+pub trait Solver: Sync {
+    fn parts(&self) -> usize;
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError>;
+}
+
+/// A parsed puzzle input, ready to be solved.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> String;
+}
+
+struct Model<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Model<S> {
+    fn solve(&self, part: Part) -> String {
+        match part {
+            Part::One => S::part1(&self.0).to_string(),
+            Part::Two => S::part2(&self.0).to_string(),
+        }
+    }
+}
+
+impl<S: Solution + Sync + 'static> Solver for S {
+    fn parts(&self) -> usize {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        Ok(Box::new(Model::<S>(S::parse(input)?)))
+    }
+}
@@ -0,0 +1,129 @@
+//! Runs every day against its example and real inputs, and checks the results against the
+//! answers recorded in `answers.txt`.
+
+use aoc::days;
+use aoc::Part;
+use std::fs;
+use std::path::Path;
+
+struct Answer {
+    day: usize,
+    input: &'static str,
+    parts: [&'static str; 2],
+}
+
+fn recorded_answers() -> Vec<Answer> {
+    include_str!("answers.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [day, input, part1, part2] => Answer {
+                    day: day.parse().expect("day should be a number"),
+                    input,
+                    parts: [part1, part2],
+                },
+                _ => panic!("malformed line in answers.txt: {:?}", line),
+            }
+        })
+        .collect()
+}
+
+fn check_day(number: usize) {
+    let day = days::find(number).unwrap();
+    let mut failures = Vec::new();
+
+    for answer in recorded_answers()
+        .iter()
+        .filter(|answer| answer.day == number)
+    {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/days")
+            .join(format!("day{}", number))
+            .join(answer.input);
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("couldn't read {}: {}", path.display(), err));
+        let parsed = day
+            .parse(&input)
+            .unwrap_or_else(|err| panic!("{}: {}", answer.input, err));
+
+        for part in day.parts() {
+            let expected = match part {
+                Part::One => answer.parts[0],
+                Part::Two => answer.parts[1],
+            };
+            if expected == "-" {
+                continue;
+            }
+            let actual = parsed.solve(part);
+            if actual != expected {
+                failures.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    answer.input, part, expected, actual
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "day {} answers changed:\n{}",
+        number,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn every_day_has_a_recorded_answer() {
+    let answers = recorded_answers();
+    for number in 1..=days::DAYS.len() {
+        assert!(
+            answers
+                .iter()
+                .any(|answer| answer.day == number && answer.input == "input.txt"),
+            "no answer recorded for day {}",
+            number
+        );
+    }
+}
+
+macro_rules! answer_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day1: 1,
+    day2: 2,
+    day3: 3,
+    day4: 4,
+    day5: 5,
+    day6: 6,
+    day7: 7,
+    day8: 8,
+    day9: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+    day20: 20,
+    day21: 21,
+    day22: 22,
+    day23: 23,
+    day24: 24,
+    day25: 25,
+}